@@ -1,30 +1,179 @@
-use clap::{Parser, Subcommand};
+use crate::network::Amount;
+use crate::output::OutputFormat;
+use crate::utils::attribute_utils::{self, NamespaceArg};
+use crate::utils::settings_utils::{CollectionLock, MintTypeArg, OrNone};
+use clap::{Args, Parser, Subcommand};
 
 #[derive(Parser)]
 #[command(name = "polkacli")]
 #[command(about = "CLI for interacting with AssetHub")]
 pub struct Cli {
+    /// Named account to sign with, instead of the configured default
+    #[arg(long, global = true, value_name = "NAME")]
+    pub from: Option<String>,
+
+    /// Network profile to connect to: polkadot-assethub, kusama-assethub, paseo-assethub or local
+    #[arg(long, global = true, value_name = "NETWORK")]
+    pub network: Option<String>,
+
+    /// Sign and simulate extrinsics, estimating their fees, without broadcasting them
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    /// Output format; `json` prints a single JSON object per command
+    #[arg(long, global = true, value_enum, default_value = "text")]
+    pub output: OutputFormat,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
+/// The command wrapped by `tx build`, parsed on its own since clap cannot nest `Commands` in itself.
+#[derive(Parser)]
+#[command(name = "polkacli tx build", no_binary_name = true)]
+pub struct BuildCommand {
+    #[command(subcommand)]
+    pub command: Commands,
+}
+
+/// Options of `mint-collection`.
+#[derive(Args)]
+pub struct MintCollectionArgs {
+    /// Folder containing JSON metadata files
+    #[arg(long, conflicts_with = "manifest")]
+    pub json: Option<String>,
+    /// Folder containing images
+    #[arg(long, conflicts_with = "manifest")]
+    pub image: Option<String>,
+    /// CSV or JSON file mapping item IDs to their JSON metadata and image files
+    #[arg(long, value_name = "FILE")]
+    pub manifest: Option<String>,
+    /// Journal of completed items (defaults to `<manifest or folder>.journal.jsonl`)
+    #[arg(long, value_name = "FILE")]
+    pub journal: Option<String>,
+    /// Continue an interrupted run from its journal, in the collection it created
+    #[arg(long)]
+    pub resume: bool,
+    /// Submit one extrinsic per call instead of packing items into `Utility::batch_all` extrinsics
+    #[arg(long)]
+    pub no_batch: bool,
+    /// Number of batch extrinsics awaiting finalization at the same time
+    #[arg(long, value_name = "N", default_value_t = 4, conflicts_with = "no_batch")]
+    pub concurrency: usize,
+    /// JSON metadata of the collection itself
+    #[arg(long, value_name = "FILE")]
+    pub collection_json: Option<String>,
+    /// Image of the collection (defaults to the image named after --collection-json)
+    #[arg(long, value_name = "FILE", requires = "collection_json")]
+    pub collection_image: Option<String>,
+    /// Maximum number of items in the collection
+    #[arg(long, value_name = "N")]
+    pub max_supply: Option<u32>,
+    /// Collection settings to lock for good, separated by commas
+    #[arg(long, value_enum, value_name = "SETTINGS", value_delimiter = ',')]
+    pub lock_collection: Vec<CollectionLock>,
+    #[command(flatten)]
+    pub mint_settings: MintSettingsArgs,
+}
+
+/// Options of `presign-mint`.
+#[derive(Args)]
+pub struct PresignMintArgs {
+    #[arg(value_name = "COLLECTION_ID")]
+    pub collection_id: u32,
+    #[arg(value_name = "NFT_ID")]
+    pub nft_id: u32,
+    /// Path to JSON metadata file
+    #[arg(long)]
+    pub json: Option<String>,
+    /// Path to image file
+    #[arg(long)]
+    pub image: Option<String>,
+    /// Also authorize the JSON's `attributes` as on-chain attributes in the collection owner namespace
+    #[arg(long, requires = "json")]
+    pub attributes: bool,
+    /// Price in tokens the redeemer pays to the collection owner
+    #[arg(long, value_name = "AMOUNT")]
+    pub price: Option<Amount>,
+    /// Only this account can redeem the mint
+    #[arg(long, value_name = "ADDRESS")]
+    pub buyer: Option<String>,
+    /// Number of blocks the authorization stays valid for (defaults to the longest the chain allows)
+    #[arg(long, value_name = "BLOCKS")]
+    pub duration: Option<u32>,
+    /// Write the signed authorization to a JSON file for `redeem-mint`
+    #[arg(long, value_name = "FILE")]
+    pub out: Option<String>,
+}
+
+/// Options of `presign-attributes`.
+#[derive(Args)]
+pub struct PresignAttributesArgs {
+    #[arg(value_name = "COLLECTION_ID")]
+    pub collection_id: u32,
+    #[arg(value_name = "NFT_ID")]
+    pub nft_id: u32,
+    /// Attribute to authorize, as KEY=VALUE (repeatable)
+    #[arg(long = "attribute", value_name = "KEY=VALUE", value_parser = attribute_utils::parse_key_value)]
+    pub attributes: Vec<(String, String)>,
+    /// Also authorize the `attributes` of this JSON metadata file
+    #[arg(long, required_unless_present = "attributes")]
+    pub json: Option<String>,
+    /// Namespace of the attributes: the collection owner's (signed by an admin) or the signer's own
+    #[arg(long, value_enum, default_value = "collection-owner")]
+    pub namespace: NamespaceArg,
+    /// Number of blocks the authorization stays valid for (defaults to the longest the chain allows)
+    #[arg(long, value_name = "BLOCKS")]
+    pub duration: Option<u32>,
+    /// Write the signed authorization to a JSON file for `redeem-attributes`
+    #[arg(long, value_name = "FILE")]
+    pub out: Option<String>,
+}
+
+/// Mint settings of a collection, shared by `mint-collection` and `update-mint-settings`.
+///
+/// Options left out keep the collection's current value, or the default for a new collection.
+#[derive(Args)]
+pub struct MintSettingsArgs {
+    /// Who can mint items
+    #[arg(long, value_enum)]
+    pub mint_type: Option<MintTypeArg>,
+    /// Collection whose item holders can mint, with `--mint-type holder-of`
+    #[arg(long, value_name = "COLLECTION_ID", required_if_eq("mint_type", "holder-of"))]
+    pub holder_of: Option<u32>,
+    /// Price of minting an item in tokens, or `none` for free minting
+    #[arg(long, value_name = "AMOUNT|none")]
+    pub mint_price: Option<OrNone<Amount>>,
+    /// First block at which items can be minted, or `none`
+    #[arg(long, value_name = "BLOCK|none")]
+    pub mint_start: Option<OrNone<u32>>,
+    /// Last block at which items can be minted, or `none`
+    #[arg(long, value_name = "BLOCK|none")]
+    pub mint_end: Option<OrNone<u32>>,
+    /// Make new items non-transferable (`=false` to unlock)
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    pub lock_item_transfers: Option<bool>,
+    /// Lock the metadata of new items (`=false` to unlock)
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    pub lock_item_metadata: Option<bool>,
+    /// Lock the attributes of new items (`=false` to unlock)
+    #[arg(long, value_name = "BOOL", num_args = 0..=1, require_equals = true, default_missing_value = "true")]
+    pub lock_item_attributes: Option<bool>,
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
     /// Mint a new NFT collection
-    MintCollection {
-        /// Folder containing JSON metadata files
-        #[arg(long)]
-        json: Option<String>,
-        /// Folder containing images
-        #[arg(long)]
-        image: Option<String>,
-    },
+    MintCollection(MintCollectionArgs),
+    /// List the NFTs owned by an account (defaults to the configured account)
     #[cfg(feature = "nft")]
     ListNfts {
-
         #[arg(value_name = "ADDRESS")]
         address: Option<String>,
-   },
+        /// Only list items of this collection
+        #[arg(long, value_name = "COLLECTION_ID")]
+        collection: Option<u32>,
+    },
     /// Mint a new NFT within a collection
     MintNft {
         #[arg(value_name = "COLLECTION_ID")]
@@ -37,8 +186,251 @@ pub enum Commands {
         /// Path to image file
         #[arg(long)]
         image: Option<String>,
+        /// Also set the JSON's `attributes` as on-chain attributes in the collection owner namespace
+        #[arg(long, requires = "json")]
+        attributes: bool,
     },
-    /// Mint a new NFT within a collection
+    /// Sign an authorization to mint an NFT off-chain, so that another account can mint it
+    PresignMint(PresignMintArgs),
+    /// Mint an NFT with an authorization from `presign-mint`, paying its fee and price
+    RedeemMint {
+        /// File written by `presign-mint`, or its JSON content
+        #[arg(value_name = "AUTHORIZATION")]
+        authorization: String,
+    },
+    /// Sign an authorization to set attributes of an NFT off-chain, so that its owner can set them
+    PresignAttributes(PresignAttributesArgs),
+    /// Set the attributes of an NFT you own with an authorization from `presign-attributes`
+    RedeemAttributes {
+        /// File written by `presign-attributes`, or its JSON content
+        #[arg(value_name = "AUTHORIZATION")]
+        authorization: String,
+    },
+    /// Set an on-chain attribute of a collection, or of one of its NFTs with --item
+    SetAttribute {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "KEY")]
+        key: String,
+        #[arg(value_name = "VALUE")]
+        value: String,
+        /// NFT to set the attribute of, instead of the collection
+        #[arg(long, value_name = "NFT_ID")]
+        item: Option<u32>,
+        /// Namespace of the attribute
+        #[arg(long, value_enum, default_value = "collection-owner")]
+        namespace: NamespaceArg,
+    },
+    /// Remove an on-chain attribute of a collection, or of one of its NFTs with --item
+    ClearAttribute {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "KEY")]
+        key: String,
+        /// NFT to clear the attribute of, instead of the collection
+        #[arg(long, value_name = "NFT_ID")]
+        item: Option<u32>,
+        /// Namespace of the attribute
+        #[arg(long, value_enum, default_value = "collection-owner")]
+        namespace: NamespaceArg,
+    },
+    /// Set the accounts holding the Issuer, Admin and Freezer roles of a collection
+    SetTeam {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        /// Account that can mint items, or `none` (defaults to the current issuer)
+        #[arg(long, value_name = "ADDRESS|none")]
+        issuer: Option<OrNone<String>>,
+        /// Account that can administer items, or `none` (defaults to the current admin)
+        #[arg(long, value_name = "ADDRESS|none")]
+        admin: Option<OrNone<String>>,
+        /// Account that can freeze items, or `none` (defaults to the current freezer)
+        #[arg(long, value_name = "ADDRESS|none")]
+        freezer: Option<OrNone<String>>,
+    },
+    /// Hand a collection over to a new owner who accepted it with `accept-ownership`
+    TransferOwnership {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "NEW_OWNER")]
+        new_owner: String,
+    },
+    /// Agree to receive ownership of a collection, or `none` to withdraw
+    AcceptOwnership {
+        #[arg(value_name = "COLLECTION_ID|none")]
+        collection_id: OrNone<u32>,
+    },
+    /// Allow a delegate to transfer an NFT
+    ApproveTransfer {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "NFT_ID")]
+        nft_id: u32,
+        /// Address of the delegate
+        #[arg(value_name = "DELEGATE")]
+        delegate: String,
+        /// Number of blocks the approval lasts (defaults to until cancelled)
+        #[arg(long, value_name = "BLOCKS")]
+        duration: Option<u32>,
+    },
+    /// Revoke the transfer approval of a delegate
+    CancelApproval {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "NFT_ID")]
+        nft_id: u32,
+        /// Address of the delegate
+        #[arg(value_name = "DELEGATE")]
+        delegate: String,
+    },
+    /// Revoke the transfer approvals of every delegate of an NFT
+    ClearApprovals {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "NFT_ID")]
+        nft_id: u32,
+    },
+    /// List an NFT for sale, or delist it with `none`
+    SetPrice {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "NFT_ID")]
+        nft_id: u32,
+        /// Price in tokens, or `none` to delist the NFT
+        #[arg(value_name = "PRICE|none")]
+        price: OrNone<Amount>,
+        /// Only this account may buy the NFT
+        #[arg(long, value_name = "ADDRESS")]
+        buyer: Option<String>,
+    },
+    /// Buy an NFT listed for sale
+    BuyNft {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "NFT_ID")]
+        nft_id: u32,
+        /// Most to pay in tokens (defaults to the current price)
+        #[arg(long, value_name = "AMOUNT")]
+        max_price: Option<Amount>,
+    },
+    /// Offer, claim and cancel atomic NFT swaps
+    Swap {
+        #[command(subcommand)]
+        command: SwapCommands,
+    },
+    /// Change who can mint items of a collection and on what terms
+    UpdateMintSettings {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[command(flatten)]
+        mint_settings: MintSettingsArgs,
+    },
+    /// Set the maximum number of items in a collection
+    SetCollectionMaxSupply {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "MAX_SUPPLY")]
+        max_supply: u32,
+    },
+    /// Lock collection settings for good (needs the Freezer role)
+    LockCollection {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        /// Collection settings to lock, separated by commas
+        #[arg(value_enum, value_name = "SETTINGS", value_delimiter = ',', required = true)]
+        locks: Vec<CollectionLock>,
+    },
+    /// Overwrite the configuration of a collection, unlocking settings if needed (needs the force origin)
+    ForceCollectionConfig {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        /// Maximum number of items in the collection, or `none`
+        #[arg(long, value_name = "N|none")]
+        max_supply: Option<OrNone<u32>>,
+        /// Collection settings to lock, separated by commas
+        #[arg(long, value_enum, value_name = "SETTINGS", value_delimiter = ',')]
+        lock_collection: Vec<CollectionLock>,
+        /// Collection settings to unlock, separated by commas
+        #[arg(long, value_enum, value_name = "SETTINGS", value_delimiter = ',')]
+        unlock_collection: Vec<CollectionLock>,
+        #[command(flatten)]
+        mint_settings: MintSettingsArgs,
+    },
+    /// Lock the transfers of an NFT (needs the Freezer role)
+    FreezeNft {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "NFT_ID")]
+        nft_id: u32,
+    },
+    /// Unlock the transfers of a frozen NFT (needs the Freezer role)
+    ThawNft {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "NFT_ID")]
+        nft_id: u32,
+    },
+    /// Lock the metadata and/or attributes of an NFT for good (needs the Admin role)
+    LockNft {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "NFT_ID")]
+        nft_id: u32,
+        /// Lock the NFT's metadata
+        #[arg(long)]
+        metadata: bool,
+        /// Lock the NFT's attributes in the collection owner namespace
+        #[arg(long)]
+        attributes: bool,
+    },
+    /// Pin a collection's JSON metadata and image to IPFS and set it as its metadata
+    SetCollectionMetadata {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        /// Path to JSON metadata file
+        #[arg(long)]
+        json: String,
+        /// Path to image file
+        #[arg(long)]
+        image: Option<String>,
+    },
+    /// Remove the metadata of a collection
+    ClearCollectionMetadata {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+    },
+    /// Transfer an NFT to another account
+    TransferNft {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "NFT_ID")]
+        nft_id: u32,
+        /// Address of the new owner
+        #[arg(value_name = "DEST")]
+        dest: String,
+    },
+    /// Burn an NFT, destroying it permanently
+    BurnNft {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "NFT_ID")]
+        nft_id: u32,
+        /// Burn without asking for confirmation
+        #[arg(long, short)]
+        yes: bool,
+    },
+    /// Destroy a collection and reclaim its deposits
+    DestroyCollection {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        /// Burn the remaining items first; they must all be owned by the signing account
+        #[arg(long)]
+        burn_items: bool,
+        /// Destroy without asking for confirmation
+        #[arg(long, short)]
+        yes: bool,
+    },
+    /// Set the metadata of an existing NFT
     SetNftMetadata {
         #[arg(value_name = "COLLECTION_ID")]
         collection_id: u32,
@@ -70,6 +462,28 @@ pub enum Commands {
     ShowCollection {
         #[arg(value_name = "COLLECTION_ID")]
         collection_id: u32,
+
+        /// List the collection's items with their owners, approvals, locks and metadata
+        #[arg(long)]
+        items: bool,
+
+        /// Resolve item names from their JSON metadata, fetching it from IPFS when linked
+        #[arg(long, requires = "items")]
+        names: bool,
+
+        /// Number of items listed per page
+        #[arg(long, value_name = "N", default_value_t = 50, requires = "items", value_parser = clap::value_parser!(u32).range(1..=1000))]
+        page_size: u32,
+
+        /// Continue the item listing after this item, as printed at the end of the previous page
+        #[arg(long, value_name = "ITEM_ID", requires = "items")]
+        after: Option<u32>,
+    },
+
+    /// List the collections an account owns or holds a role in (defaults to the configured account)
+    ListCollections {
+        #[arg(value_name = "ADDRESS")]
+        address: Option<String>,
     },
 
     /// Set the account to use for CLI
@@ -79,6 +493,26 @@ pub enum Commands {
 
         #[arg(long, value_name = "SECRET_URI", conflicts_with = "mnemonic")]
         secret_uri: Option<String>,
+
+        /// Name to store the account under (defaults to "default")
+        #[arg(long, value_name = "NAME")]
+        name: Option<String>,
+    },
+
+    /// Import a polkadot-js JSON keystore as the account to use for CLI
+    ImportKeystore {
+        #[arg(value_name = "FILE")]
+        path: String,
+
+        /// Name to store the account under (defaults to "default")
+        #[arg(long, value_name = "NAME")]
+        name: Option<String>,
+    },
+
+    /// Export the selected account as a polkadot-js JSON keystore
+    ExportKeystore {
+        #[arg(value_name = "FILE")]
+        path: String,
     },
 
     /// Set the RPC URL for the client
@@ -87,13 +521,19 @@ pub enum Commands {
         rpc_url: String,
     },
 
+    /// Set the default network profile
+    SetNetwork {
+        #[arg(value_name = "NETWORK")]
+        network: String,
+    },
+
     /// Send funds to an address
     Send {
         #[arg(value_name = "ADDRESS")]
         address: String,
 
         #[arg(value_name = "AMOUNT")]
-        amount: f64,
+        amount: Amount,
     },
 
     /// Print the balance of the configured account or a provided address
@@ -102,10 +542,167 @@ pub enum Commands {
         address: Option<String>,
     },
 
-    /// Retrieve information for a given account by public key
+    /// Retrieve information for a given account by public key, or manage named accounts
+    #[command(args_conflicts_with_subcommands = true)]
     Account {
+        #[command(subcommand)]
+        command: Option<AccountCommands>,
+
         #[arg(value_name = "PUBLIC_KEY")]
-        public_key: String,
+        public_key: Option<String>,
+    },
+
+    /// Read or change values in the config file
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
+    /// Build, sign and submit transactions separately, e.g. to sign on an offline machine
+    Tx {
+        #[command(subcommand)]
+        command: TxCommands,
     },
 }
 
+#[derive(Subcommand)]
+pub enum AccountCommands {
+    /// Add a named account from a mnemonic, secret URI or JSON keystore
+    Add {
+        #[arg(value_name = "NAME")]
+        name: String,
+
+        #[arg(long, value_name = "MNEMONIC", conflicts_with_all = ["secret_uri", "keystore"])]
+        mnemonic: Option<String>,
+
+        #[arg(long, value_name = "SECRET_URI", conflicts_with_all = ["mnemonic", "keystore"])]
+        secret_uri: Option<String>,
+
+        /// Path to a polkadot-js JSON keystore
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["mnemonic", "secret_uri"])]
+        keystore: Option<String>,
+    },
+
+    /// List the named accounts
+    List,
+
+    /// Make a named account the default signer
+    Use {
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+}
+
+
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+    /// Print a value, addressed by a dotted key such as `defaults.network`
+    Get {
+        #[arg(value_name = "KEY")]
+        key: String,
+    },
+
+    /// Set a value, addressed by a dotted key such as `ipfs.pinata_jwt`
+    Set {
+        #[arg(value_name = "KEY")]
+        key: String,
+
+        #[arg(value_name = "VALUE")]
+        value: String,
+    },
+
+    /// Remove a value, addressed by a dotted key
+    Unset {
+        #[arg(value_name = "KEY")]
+        key: String,
+    },
+
+    /// Print the whole config file with secrets masked
+    Show,
+}
+
+#[derive(Subcommand)]
+pub enum SwapCommands {
+    /// Offer an NFT in exchange for an NFT of another collection
+    Create {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "NFT_ID")]
+        nft_id: u32,
+        /// Collection of the wanted NFT
+        #[arg(long, value_name = "COLLECTION_ID")]
+        for_collection: u32,
+        /// The wanted NFT (defaults to any NFT of --for-collection)
+        #[arg(long, value_name = "NFT_ID")]
+        for_nft: Option<u32>,
+        /// Tokens to pay on top of the offered NFT
+        #[arg(long, value_name = "AMOUNT", conflicts_with = "ask")]
+        pay: Option<Amount>,
+        /// Tokens to ask from whoever claims the swap
+        #[arg(long, value_name = "AMOUNT")]
+        ask: Option<Amount>,
+        /// Number of blocks the offer stays open for
+        #[arg(long, value_name = "BLOCKS")]
+        duration: u32,
+    },
+
+    /// Give an NFT in exchange for the NFT offered in a swap
+    Claim {
+        /// Collection of the NFT to give
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        /// The NFT to give
+        #[arg(value_name = "NFT_ID")]
+        nft_id: u32,
+        /// Collection of the offered NFT
+        #[arg(long, value_name = "COLLECTION_ID")]
+        for_collection: u32,
+        /// The offered NFT
+        #[arg(long, value_name = "NFT_ID")]
+        for_nft: u32,
+    },
+
+    /// Withdraw the swap offered for an NFT
+    Cancel {
+        #[arg(value_name = "COLLECTION_ID")]
+        collection_id: u32,
+        #[arg(value_name = "NFT_ID")]
+        nft_id: u32,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TxCommands {
+    /// Build the unsigned transaction of a command such as `send` or `mint-nft`
+    Build {
+        /// Write the unsigned transaction to a JSON file for `tx sign`
+        #[arg(long, value_name = "FILE")]
+        out: Option<String>,
+
+        /// Number of blocks the transaction stays valid for; 0 makes it immortal
+        #[arg(long, value_name = "BLOCKS", default_value_t = 1024)]
+        mortality: u64,
+
+        /// The command to build, with its arguments
+        #[arg(value_name = "COMMAND", required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+        command: Vec<String>,
+    },
+
+    /// Sign a transaction built by `tx build` with the selected account, without a connection
+    Sign {
+        /// JSON file written by `tx build`
+        #[arg(value_name = "FILE")]
+        path: String,
+
+        /// Write the signed extrinsic to a file instead of printing it
+        #[arg(long, value_name = "FILE")]
+        out: Option<String>,
+    },
+
+    /// Broadcast a signed extrinsic and wait until it is finalized
+    Submit {
+        /// Hex encoded signed extrinsic, or a file containing it
+        #[arg(value_name = "EXTRINSIC")]
+        extrinsic: String,
+    },
+}
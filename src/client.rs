@@ -1,18 +1,51 @@
+use subxt::backend::{legacy::LegacyRpcMethods, rpc::RpcClient};
 use subxt::{OnlineClient, PolkadotConfig};
-use crate::config::load_rpc_url_from_config;
+use crate::config::{load_network_from_config, load_rpc_url_from_config};
 use crate::error::Result;
+use crate::network::{self, NetworkProfile, TokenInfo, DEFAULT_NETWORK};
 use once_cell::sync::OnceCell;
 
 static CLIENT: OnceCell<OnlineClient<PolkadotConfig>> = OnceCell::new();
+static TOKEN: OnceCell<TokenInfo> = OnceCell::new();
+static LEGACY_RPC: OnceCell<LegacyRpcMethods<PolkadotConfig>> = OnceCell::new();
+
+// `--network` wins; otherwise a custom RPC URL from `set-rpc`, then the configured network profile.
+fn resolve_endpoint() -> Result<(String, NetworkProfile)> {
+    if let Some(profile) = network::selected_network() {
+        return Ok((profile.rpc_url.clone(), profile.clone()));
+    }
+
+    let profile = match load_network_from_config() {
+        Ok(Some(name)) => network::find_network(&name)?,
+        _ => network::find_network(DEFAULT_NETWORK)?,
+    };
+    let url = load_rpc_url_from_config().unwrap_or_else(|_| profile.rpc_url.clone());
+    Ok((url, profile))
+}
 
 pub async fn get_client() -> Result<&'static OnlineClient<PolkadotConfig>> {
     if CLIENT.get().is_none() {
-        let url = load_rpc_url_from_config().unwrap_or_else(|_| {
-            "wss://asset-hub-paseo-rpc.dwellir.com".to_string() // Default value if not configured
-        });
-        let client = OnlineClient::<PolkadotConfig>::from_url(&url).await?;
+        let (url, profile) = resolve_endpoint()?;
+        let rpc = RpcClient::from_url(&url).await?;
+        let client = OnlineClient::<PolkadotConfig>::from_rpc_client(rpc.clone()).await?;
+
+        let legacy_rpc = LegacyRpcMethods::<PolkadotConfig>::new(rpc);
+        let properties = legacy_rpc.system_properties().await.unwrap_or_default();
+        TOKEN.set(TokenInfo::from_properties(&properties, &profile)?).ok();
+        LEGACY_RPC.set(legacy_rpc).ok();
         CLIENT.set(client).unwrap();
     }
     Ok(CLIENT.get().unwrap())
 }
 
+/// Symbol and decimals of the connected chain's native token.
+pub async fn get_token_info() -> Result<&'static TokenInfo> {
+    get_client().await?;
+    Ok(TOKEN.get().unwrap())
+}
+
+/// Node RPC methods that are not exposed through `OnlineClient`, such as `system_dryRun`.
+pub async fn get_legacy_rpc() -> Result<&'static LegacyRpcMethods<PolkadotConfig>> {
+    get_client().await?;
+    Ok(LEGACY_RPC.get().unwrap())
+}
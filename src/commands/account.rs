@@ -1,14 +1,16 @@
 use crate::error::Result;
+use crate::output;
+use serde_json::json;
 use subxt::utils::AccountId32;
-use std::str::FromStr;
+use crate::utils::account_utils::parse_address;
 use crate::commands::assethub;
 use crate::client::get_client;
 
 pub async fn account_info(public_key: String) -> Result<()> {
     let api = get_client().await?;
-    println!("Connection with parachain established.");
+    say!("Connection with parachain established.");
 
-    let account: AccountId32 = AccountId32::from_str(&public_key)?;
+    let account: AccountId32 = parse_address(&public_key)?;
 
     let storage_query = assethub::storage().system().account(account.clone());
 
@@ -20,13 +22,24 @@ pub async fn account_info(public_key: String) -> Result<()> {
     > = api.storage().at_latest().await?.fetch(&storage_query).await?;
 
     if let Some(account_info) = result {
-        println!("Free balance: {}", account_info.data.free);
-        println!("Nonce: {}", account_info.nonce);
-        println!("Consumers: {}", account_info.consumers);
-        println!("Providers: {}", account_info.providers);
-        println!("Sufficients: {}", account_info.sufficients);
+        say!("Free balance: {}", account_info.data.free);
+        say!("Nonce: {}", account_info.nonce);
+        say!("Consumers: {}", account_info.consumers);
+        say!("Providers: {}", account_info.providers);
+        say!("Sufficients: {}", account_info.sufficients);
+        output::emit(json!({
+            "address": account.to_string(),
+            "free": account_info.data.free.to_string(),
+            "reserved": account_info.data.reserved.to_string(),
+            "frozen": account_info.data.frozen.to_string(),
+            "nonce": account_info.nonce,
+            "consumers": account_info.consumers,
+            "providers": account_info.providers,
+            "sufficients": account_info.sufficients,
+        }));
     } else {
-        println!("No account data found for account {}", public_key);
+        say!("No account data found for account {}", public_key);
+        output::emit(json!({ "address": account.to_string(), "free": null }));
     }
 
     Ok(())
@@ -0,0 +1,144 @@
+use crate::client::get_client;
+use crate::commands::assethub;
+use crate::error::Result;
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::account_utils::parse_address;
+use crate::utils::tx_utils;
+use colored::*;
+use serde_json::{json, Value};
+use subxt::utils::{AccountId32, MultiAddress};
+
+/// Allow a delegate to transfer an NFT, for `duration` blocks or until cancelled.
+pub async fn approve_transfer(collection_id: u32, nft_id: u32, delegate: String, duration: Option<u32>) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let delegate: AccountId32 = parse_address(&delegate)?;
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx()
+        .nfts()
+        .approve_transfer(collection_id, nft_id, MultiAddress::Id(delegate), duration);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🤝 Approving delegate...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Transfer approved!".green().bold().to_string());
+
+    let assethub::nfts::events::TransferApproved { collection, item, delegate, deadline, .. } = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::TransferApproved>()?
+        .ok_or("TransferApproved event not found")?;
+
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection.to_string().bright_white());
+    say!("{}: {}", "🎨 NFT ID".cyan().bold(), item.to_string().bright_white());
+    say!("{}: {}", "🤝 Delegate".cyan().bold(), delegate.to_string().bright_white());
+    match deadline {
+        Some(deadline) => say!("{}: #{}", "⏳ Expires At".cyan().bold(), deadline),
+        None => say!("{}: {}", "⏳ Expires At".cyan().bold(), "Never"),
+    }
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection);
+    result["item_id"] = json!(item);
+    result["delegate"] = json!(delegate.to_string());
+    result["deadline"] = json!(deadline);
+    output::emit(result);
+    Ok(())
+}
+
+/// Revoke the transfer approval of a delegate.
+pub async fn cancel_approval(collection_id: u32, nft_id: u32, delegate: String) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let delegate: AccountId32 = parse_address(&delegate)?;
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx()
+        .nfts()
+        .cancel_approval(collection_id, nft_id, MultiAddress::Id(delegate.clone()));
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🤝 Revoking approval...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Approval cancelled!".green().bold().to_string());
+
+    extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::ApprovalCancelled>()?
+        .ok_or("ApprovalCancelled event not found")?;
+    say!("{}: {}", "🤝 Delegate".cyan().bold(), delegate.to_string().bright_white());
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["item_id"] = json!(nft_id);
+    result["delegate"] = json!(delegate.to_string());
+    output::emit(result);
+    Ok(())
+}
+
+/// Revoke the transfer approvals of every delegate of an NFT.
+pub async fn clear_approvals(collection_id: u32, nft_id: u32) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().clear_all_transfer_approvals(collection_id, nft_id);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🤝 Revoking all approvals...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "All approvals cancelled!".green().bold().to_string());
+
+    extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::AllApprovalsCancelled>()?
+        .ok_or("AllApprovalsCancelled event not found")?;
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["item_id"] = json!(nft_id);
+    output::emit(result);
+    Ok(())
+}
+
+/// When an approval expires, relative to the current block.
+pub fn describe_deadline(deadline: Option<u32>, current_block: u32) -> String {
+    match deadline {
+        None => "never expires".to_string(),
+        Some(deadline) if deadline >= current_block => {
+            format!("expires at #{} (in {} blocks)", deadline, deadline - current_block)
+        }
+        Some(deadline) => format!("expired at #{} ({} blocks ago)", deadline, current_block - deadline),
+    }
+}
+
+pub fn approvals_to_json(approvals: &[(AccountId32, Option<u32>)], current_block: u32) -> Value {
+    approvals
+        .iter()
+        .map(|(delegate, deadline)| {
+            json!({
+                "delegate": delegate.to_string(),
+                "deadline": deadline,
+                "expired": deadline.is_some_and(|deadline| deadline < current_block),
+            })
+        })
+        .collect()
+}
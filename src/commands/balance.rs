@@ -1,18 +1,21 @@
 use crate::error::Result;
+use crate::output;
 use colored::*;
+use serde_json::json;
 use subxt::utils::AccountId32;
 use crate::commands::assethub;
-use std::str::FromStr;
-use crate::client::get_client;
+use crate::utils::account_utils::parse_address;
+use crate::client::{get_client, get_token_info};
 
 pub async fn balance(address: Option<String>) -> Result<()> {
 //    let api = OnlineClient::<PolkadotConfig>::from_url("wss://asset-hub-paseo-rpc.dwellir.com").await?;
     let api = get_client().await?;
-    println!("{}", "🚀 Connection with parachain established.".green().bold());
+    let token = get_token_info().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
 
     // Determine the account to use based on the presence of an address argument
     let account: AccountId32 = if let Some(addr) = address {
-        AccountId32::from_str(&addr)?
+        parse_address(&addr)?
     } else {
         let account_signer = crate::config::load_account_from_config()?;
         account_signer.public_key().into()
@@ -29,27 +32,33 @@ pub async fn balance(address: Option<String>) -> Result<()> {
 
     if let Some(account_info) = result {
         let free_balance = account_info.data.free;
-        let unit = 10u128.pow(10); // 1 PAS = 10^10 Plancks
-        let human_readable_balance = free_balance as f64 / unit as f64;
 
-        println!(
+        say!(
             "{} {}: {}",
             "📜 Account".cyan().bold(),
             "Address".yellow().bold(),
             account.to_string().bright_white()
         );
-        println!(
-            "{} {:.10} {}",
+        say!(
+            "{} {}",
             "💰 Free balance:".cyan().bold(),
-            human_readable_balance,
-            "PAS".bright_white()
+            token.format_plancks(free_balance).bright_white()
         );
+        output::emit(json!({
+            "address": account.to_string(),
+            "free": free_balance.to_string(),
+            "reserved": account_info.data.reserved.to_string(),
+            "frozen": account_info.data.frozen.to_string(),
+            "symbol": token.symbol,
+            "decimals": token.decimals,
+        }));
     } else {
-        println!(
+        say!(
             "{} {}",
             "❌ No account data found for account".red().bold(),
             account.to_string().bright_white()
         );
+        output::emit(json!({ "address": account.to_string(), "free": null }));
     }
 
     Ok(())
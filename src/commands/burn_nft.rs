@@ -0,0 +1,49 @@
+use crate::client::get_client;
+use crate::commands::assethub;
+use crate::error::Result;
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::{prompt_utils, tx_utils};
+use colored::*;
+use serde_json::json;
+
+pub async fn burn_nft(collection_id: u32, nft_id: u32, yes: bool) -> Result<()> {
+    prompt_utils::confirm(
+        &format!("🔥 Burn NFT {} of collection {}? This cannot be undone.", nft_id, collection_id),
+        yes,
+    )?;
+
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().burn(collection_id, nft_id);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🔥 Burning NFT...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "NFT burn finalized!".green().bold().to_string());
+
+    let burned_event = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::Burned>()?
+        .ok_or("Burned event not found")?;
+    let assethub::nfts::events::Burned { collection, item, owner } = burned_event;
+
+    say!("\n{}\n", "🔥 NFT Burned Successfully!".blue().bold());
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection.to_string().bright_white());
+    say!("{}: {}", "🎨 NFT ID".cyan().bold(), item.to_string().bright_white());
+    say!("{}: {}", "👤 Owner".cyan().bold(), owner.to_string().bright_white());
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection);
+    result["item_id"] = json!(item);
+    result["owner"] = json!(owner.to_string());
+    output::emit(result);
+    Ok(())
+}
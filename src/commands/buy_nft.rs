@@ -0,0 +1,73 @@
+use crate::client::{get_client, get_token_info};
+use crate::commands::assethub;
+use crate::error::{Error, Result};
+use crate::network::Amount;
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::tx_utils;
+use colored::*;
+use serde_json::json;
+
+/// Buy a listed NFT, paying at most `max_price` in tokens, or exactly its current price when not given.
+pub async fn buy_nft(collection_id: u32, nft_id: u32, max_price: Option<Amount>) -> Result<()> {
+    let api = get_client().await?;
+    let token = get_token_info().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let price_query = assethub::storage().nfts().item_price_of(collection_id, nft_id);
+    let (price, _) = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch(&price_query)
+        .await?
+        .ok_or_else(|| Error::invalid_input(format!("NFT {} of collection {} is not for sale.", nft_id, collection_id)))?;
+
+    // The bid caps what is paid, so a price raised before the purchase is included makes it fail.
+    let bid = match max_price {
+        Some(max_price) => token.to_plancks(&max_price)?,
+        None => price,
+    };
+    if price > bid {
+        return Err(Error::invalid_input(format!(
+            "The NFT costs {}, more than the maximum of {}.",
+            token.format_plancks(price),
+            token.format_plancks(bid)
+        )));
+    }
+    say!("{}: {}", "💰 Price".cyan().bold(), token.format_plancks(price).bright_white());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().buy_item(collection_id, nft_id, bid);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🛒 Buying NFT...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "NFT bought!".green().bold().to_string());
+
+    let assethub::nfts::events::ItemBought { collection, item, price, seller, buyer } = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::ItemBought>()?
+        .ok_or("ItemBought event not found")?;
+
+    say!("\n{}\n", "🛒 NFT Bought Successfully!".blue().bold());
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection.to_string().bright_white());
+    say!("{}: {}", "🎨 NFT ID".cyan().bold(), item.to_string().bright_white());
+    say!("{}: {}", "💰 Paid".cyan().bold(), token.format_plancks(price).bright_white());
+    say!("{}: {}", "📤 Seller".cyan().bold(), seller.to_string().bright_white());
+    say!("{}: {}", "📥 Buyer".cyan().bold(), buyer.to_string().bright_white());
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection);
+    result["item_id"] = json!(item);
+    result["price"] = json!(price.to_string());
+    result["seller"] = json!(seller.to_string());
+    result["buyer"] = json!(buyer.to_string());
+    output::emit(result);
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use crate::client::get_client;
+use crate::commands::assethub;
+use crate::error::Result;
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::attribute_utils::NamespaceArg;
+use crate::utils::tx_utils;
+use colored::*;
+use serde_json::json;
+
+use assethub::runtime_types::bounded_collections::bounded_vec::BoundedVec;
+
+pub async fn clear_attribute(collection_id: u32, item: Option<u32>, namespace: NamespaceArg, key: String) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().clear_attribute(
+        collection_id,
+        item,
+        namespace.namespace(account_signer.account_id()),
+        BoundedVec(key.clone().into_bytes()),
+    );
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🧹 Clearing attribute...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Attribute cleared!".green().bold().to_string());
+
+    extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::AttributeCleared>()?
+        .ok_or("AttributeCleared event not found")?;
+
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection_id.to_string().bright_white());
+    if let Some(item) = item {
+        say!("{}: {}", "🎨 NFT ID".cyan().bold(), item.to_string().bright_white());
+    }
+    say!("{}: {}", "🏷️ Attribute".cyan().bold(), key.bright_white());
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["item_id"] = json!(item);
+    result["key"] = json!(key);
+    output::emit(result);
+    Ok(())
+}
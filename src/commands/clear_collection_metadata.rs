@@ -0,0 +1,38 @@
+use crate::client::get_client;
+use crate::commands::assethub;
+use crate::error::Result;
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::tx_utils;
+use colored::*;
+use serde_json::json;
+
+pub async fn clear_collection_metadata(collection_id: u32) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().clear_collection_metadata(collection_id);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🧹 Clearing collection metadata...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Collection metadata cleared!".green().bold().to_string());
+
+    let assethub::nfts::events::CollectionMetadataCleared { collection } = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::CollectionMetadataCleared>()?
+        .ok_or("CollectionMetadataCleared event not found")?;
+
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection.to_string().bright_white());
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection);
+    output::emit(result);
+    Ok(())
+}
@@ -0,0 +1,267 @@
+use crate::client::{get_client, get_token_info};
+use crate::commands::assethub;
+use crate::error::{Error, Result};
+use crate::network::TokenInfo;
+use crate::output::{self, Spinner, Spinners};
+use crate::signer::AccountSigner;
+use crate::utils::prompt_utils;
+use crate::utils::tx_utils::{self, Batch, RuntimeCall, TxResult};
+use colored::*;
+use serde_json::json;
+use std::collections::BTreeMap;
+use subxt::utils::AccountId32;
+
+type CollectionDetails = assethub::runtime_types::pallet_nfts::types::CollectionDetails<AccountId32, u128>;
+
+/// Destroy a collection, returning the deposits of its metadata, attributes and items.
+///
+/// The collection must be empty, unless `burn_items` burns its remaining items first, which
+/// all have to be owned by the signer. The metadata and attributes left are removed by
+/// `destroy` itself, whose witness is read from the collection details.
+pub async fn destroy_collection(collection_id: u32, burn_items: bool, yes: bool) -> Result<()> {
+    let api = get_client().await?;
+    let token = get_token_info().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let account = account_signer.account_id();
+    let details = fetch_details(collection_id).await?;
+    if details.owner != account {
+        return Err(Error::invalid_input(format!(
+            "Collection {} is owned by {}, so only that account can destroy it.",
+            collection_id, details.owner
+        )));
+    }
+
+    let items = if details.items == 0 {
+        Vec::new()
+    } else if burn_items {
+        remaining_items(collection_id, &account).await?
+    } else {
+        return Err(Error::invalid_input(format!(
+            "Collection {} still has {} items. Burn them first, or pass --burn-items.",
+            collection_id, details.items
+        )));
+    };
+
+    let question = match items.len() {
+        0 => format!("💥 Destroy collection {}? This cannot be undone.", collection_id),
+        count => format!("💥 Burn the {} items of collection {} and destroy it? This cannot be undone.", count, collection_id),
+    };
+    prompt_utils::confirm(&question, yes)?;
+
+    let mut refunds = BTreeMap::new();
+    let mut burn_extrinsics = Vec::new();
+    if !items.is_empty() {
+        // Burns change the counts of the witness, so they are finalized before it is read.
+        let mut burned = Vec::new();
+        let finished = burn(collection_id, &items, &account_signer, |batch, extrinsic_result| {
+            add_refunds(&extrinsic_result, &mut refunds)?;
+            burn_extrinsics.push(format!("{:?}", extrinsic_result.extrinsic_hash()));
+            burned.extend_from_slice(batch);
+            Ok(())
+        })
+        .await;
+        match finished {
+            Ok(true) => {}
+            Ok(false) => return Ok(()),
+            Err(e) => {
+                report_partial_burn(collection_id, &burned, &burn_extrinsics, &refunds, token);
+                return Err(e);
+            }
+        }
+    }
+
+    let details = fetch_details(collection_id).await?;
+    let witness = assethub::runtime_types::pallet_nfts::types::DestroyWitness {
+        item_metadatas: details.item_metadatas,
+        item_configs: details.item_configs,
+        attributes: details.attributes,
+    };
+    say!("\n{}\n", "📋 Destroy Witness".blue().bold());
+    say!("{}: {}", "Item Metadatas".cyan().bold(), witness.item_metadatas.to_string().bright_white());
+    say!("{}: {}", "Item Configs".cyan().bold(), witness.item_configs.to_string().bright_white());
+    say!("{}: {}", "Attributes".cyan().bold(), witness.attributes.to_string().bright_white());
+    let witness_json = json!({
+        "item_metadatas": witness.item_metadatas,
+        "item_configs": witness.item_configs,
+        "attributes": witness.attributes,
+    });
+
+    let payload = assethub::tx().nfts().destroy(collection_id, witness);
+    let mut sp = Spinner::new(Spinners::Dots12, "💥 Destroying collection...".yellow().bold().to_string());
+    let extrinsic_result = match tx_utils::submit(&payload, &account_signer).await {
+        Ok(Some(extrinsic_result)) => extrinsic_result,
+        Ok(None) => return Ok(()),
+        Err(e) => {
+            drop(sp);
+            report_partial_burn(collection_id, &items, &burn_extrinsics, &refunds, token);
+            return Err(e);
+        }
+    };
+    sp.stop_and_persist("✅", "Collection destroyed!".green().bold().to_string());
+
+    extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::Destroyed>()?
+        .ok_or("Destroyed event not found")?;
+    add_refunds(&extrinsic_result, &mut refunds)?;
+
+    say!("\n{}\n", "💥 Collection Destroyed Successfully!".blue().bold());
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection_id.to_string().bright_white());
+    if !items.is_empty() {
+        say!("{}: {}", "🔥 Items Burned".cyan().bold(), items.len().to_string().bright_white());
+    }
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+    say!("\n{}\n", "💰 Deposits Refunded".blue().bold());
+    if refunds.is_empty() {
+        say!("{}", "No deposits were held.".dimmed());
+    }
+    for (who, amount) in &refunds {
+        say!("{}: {}", who.to_string().cyan().bold(), token.format_plancks(*amount).bright_white());
+    }
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["burned_items"] = json!(items);
+    result["burn_extrinsics"] = json!(burn_extrinsics);
+    result["witness"] = witness_json;
+    result["refunds"] = refunds_json(&refunds);
+    output::emit(result);
+    Ok(())
+}
+
+async fn fetch_details(collection_id: u32) -> Result<CollectionDetails> {
+    let api = get_client().await?;
+    let query = assethub::storage().nfts().collection(collection_id);
+    api.storage()
+        .at_latest()
+        .await?
+        .fetch(&query)
+        .await?
+        .ok_or_else(|| Error::invalid_input(format!("Collection {} not found.", collection_id)))
+}
+
+// The items left in the collection, which only their owner can burn.
+async fn remaining_items(collection_id: u32, account: &AccountId32) -> Result<Vec<u32>> {
+    let api = get_client().await?;
+    let address = assethub::storage().nfts().item_iter1(collection_id);
+    let mut entries = api.storage().at_latest().await?.iter(address).await?;
+    let mut items = Vec::new();
+    let mut foreign = Vec::new();
+    while let Some(entry) = entries.next().await {
+        let entry = entry?;
+        // Keys end with the item ID after its Blake2_128Concat hash.
+        let item_id = u32::from_le_bytes(entry.key_bytes[entry.key_bytes.len() - 4..].try_into().expect("four bytes"));
+        if entry.value.owner == *account {
+            items.push(item_id);
+        } else {
+            foreign.push(item_id);
+        }
+    }
+    if !foreign.is_empty() {
+        foreign.sort_unstable();
+        let ids: Vec<String> = foreign.iter().map(u32::to_string).collect();
+        return Err(Error::invalid_input(format!(
+            "Items {} of collection {} are owned by other accounts, so they cannot be burned.",
+            ids.join(", "),
+            collection_id
+        )));
+    }
+    items.sort_unstable();
+    Ok(items)
+}
+
+// Burn the items with as few `Utility::batch_all` extrinsics as possible, one after the other,
+// passing the items and result of each finalized batch to `on_burned`.
+// Returns false with `--dry-run` or `tx build`, which only cover the first batch.
+async fn burn<F>(collection_id: u32, items: &[u32], account_signer: &AccountSigner, mut on_burned: F) -> Result<bool>
+where
+    F: FnMut(&[u32], TxResult) -> Result<()>,
+{
+    use assethub::runtime_types::pallet_nfts::pallet::Call;
+
+    let mut batch = Batch::new().await?;
+    let mut payloads = Vec::new();
+    let mut batched = Vec::new();
+    for &item in items {
+        let call = vec![RuntimeCall::Nfts(Call::burn { collection: collection_id, item })];
+        if !batch.try_push(call.clone()).await? {
+            payloads.push((batch.take(), std::mem::take(&mut batched)));
+            batch.try_push(call).await?;
+        }
+        batched.push(item);
+    }
+    payloads.push((batch.take(), batched));
+
+    for (index, (payload, batched)) in payloads.iter().enumerate() {
+        let mut sp = Spinner::new(
+            Spinners::Dots12,
+            format!("🔥 Burning items (batch {} of {})...", index + 1, payloads.len()).yellow().bold().to_string(),
+        );
+        let Some(extrinsic_result) = tx_utils::submit(payload, account_signer).await? else {
+            return Ok(false);
+        };
+        sp.stop_and_persist("✅", format!("Batch {} of {} burned.", index + 1, payloads.len()).green().bold().to_string());
+        on_burned(batched, extrinsic_result)?;
+    }
+    Ok(true)
+}
+
+// Show what the finalized batches did before a later one failed, since their items are gone
+// and their deposits returned for good; with `--output json` it is emitted with the error.
+fn report_partial_burn(
+    collection_id: u32,
+    burned: &[u32],
+    burn_extrinsics: &[String],
+    refunds: &BTreeMap<AccountId32, u128>,
+    token: &TokenInfo,
+) {
+    if !burn_extrinsics.is_empty() {
+        say!("\n{}\n", "⚠️ Collection Not Destroyed".yellow().bold());
+        say!("{}: {}", "📦 Collection ID".cyan().bold(), collection_id.to_string().bright_white());
+        say!(
+            "{}: {} (in {} batches)",
+            "🔥 Items Burned".cyan().bold(),
+            burned.len().to_string().bright_white(),
+            burn_extrinsics.len()
+        );
+        for extrinsic_hash in burn_extrinsics {
+            say!("{}: {}", "🔗 Extrinsic Hash".cyan().bold(), extrinsic_hash.bright_white());
+        }
+        say!("\n{}\n", "💰 Deposits Refunded".blue().bold());
+        if refunds.is_empty() {
+            say!("{}", "No deposits were held.".dimmed());
+        }
+        for (who, amount) in refunds {
+            say!("{}: {}", who.to_string().cyan().bold(), token.format_plancks(*amount).bright_white());
+        }
+        say!("{}", "Run the command again to burn the remaining items and destroy the collection.".dimmed());
+    }
+    output::set_partial(json!({
+        "collection_id": collection_id,
+        "burned_items": burned,
+        "burn_extrinsics": burn_extrinsics,
+        "refunds": refunds_json(refunds),
+    }));
+}
+
+fn refunds_json(refunds: &BTreeMap<AccountId32, u128>) -> serde_json::Value {
+    refunds
+        .iter()
+        .map(|(who, amount)| json!({ "account": who.to_string(), "amount": amount.to_string() }))
+        .collect()
+}
+
+// Add up the deposits an extrinsic returned, per account.
+fn add_refunds(extrinsic_result: &TxResult, refunds: &mut BTreeMap<AccountId32, u128>) -> Result<()> {
+    for event in extrinsic_result.events.find::<assethub::balances::events::Unreserved>() {
+        let event = event?;
+        *refunds.entry(event.who).or_default() += event.amount;
+    }
+    Ok(())
+}
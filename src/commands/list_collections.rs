@@ -0,0 +1,145 @@
+use crate::client::get_client;
+use crate::commands::assethub;
+use crate::error::{Error, Result};
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::account_utils::parse_address;
+use crate::utils::ipfs_utils;
+use crate::utils::settings_utils::role_names;
+use colored::*;
+use serde_json::json;
+use std::collections::BTreeMap;
+use subxt::utils::AccountId32;
+use tokio::task::JoinSet;
+
+/// Number of collections whose details are fetched at the same time.
+const DETAIL_REQUESTS: usize = 16;
+
+/// A collection the account owns or holds roles in, with a summary of its details.
+struct ListedCollection {
+    collection_id: u32,
+    roles: Vec<&'static str>,
+    items: u32,
+    max_supply: Option<u32>,
+    metadata: Option<String>,
+    name: Option<String>,
+}
+
+pub async fn list_collections(address: Option<String>) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let account: AccountId32 = match address {
+        Some(address) => parse_address(&address)?,
+        None => crate::config::load_address_from_config()?,
+    };
+
+    let mut sp = Spinner::new(Spinners::Dots12, "⏳ Retrieving collections...".yellow().bold().to_string());
+    let storage = api.storage().at_latest().await?;
+    let mut roles: BTreeMap<u32, Vec<&'static str>> = BTreeMap::new();
+
+    // Keys end with the collection ID, after the Blake2_128Concat hashed account.
+    let mut owned = storage.iter(assethub::storage().nfts().collection_account_iter1(account.clone())).await?;
+    while let Some(entry) = owned.next().await {
+        let key = entry?.key_bytes;
+        roles.entry(u32_at(&key, key.len() - 4)).or_default().push("Owner");
+    }
+
+    // Roles are keyed by collection first, so every assignment is scanned for the account,
+    // which ends the key after its Blake2_128Concat hash.
+    let mut assigned = storage.iter(assethub::storage().nfts().collection_role_of_iter()).await?;
+    while let Some(entry) = assigned.next().await {
+        let entry = entry?;
+        let key = &entry.key_bytes;
+        if key[key.len() - 32..] != account.0 {
+            continue;
+        }
+        let collection_id = u32_at(key, key.len() - 52);
+        roles.entry(collection_id).or_default().extend(role_names(entry.value.0));
+    }
+
+    let mut collections = Vec::with_capacity(roles.len());
+    let roles: Vec<_> = roles.into_iter().collect();
+    for chunk in roles.chunks(DETAIL_REQUESTS) {
+        let mut requests = JoinSet::new();
+        for (collection_id, roles) in chunk {
+            requests.spawn(fetch_collection(*collection_id, roles.clone()));
+        }
+        while let Some(collection) = requests.join_next().await {
+            // A collection may be destroyed between listing its roles and reading its details.
+            if let Some(collection) = collection.map_err(|e| Error::Other(format!("Collection request failed: {}", e)))?? {
+                collections.push(collection);
+            }
+        }
+    }
+    collections.sort_unstable_by_key(|collection| collection.collection_id);
+    sp.stop_and_persist("✅", "Collections retrieved!".green().bold().to_string());
+
+    say!("\n{} {}\n", "🏛️ Collections of".blue().bold(), account.to_string().bright_white());
+    if collections.is_empty() {
+        say!("{}", "No collections found.".dimmed());
+    }
+    for collection in &collections {
+        let supply = match collection.max_supply {
+            Some(max_supply) => format!("{} / {}", collection.items, max_supply),
+            None => collection.items.to_string(),
+        };
+        say!(
+            "{}: {} | {} | {}: {} | {}",
+            "📦 Collection".cyan().bold(),
+            collection.collection_id.to_string().bright_white(),
+            collection.roles.join(", ").yellow(),
+            "🎨 Items".cyan().bold(),
+            supply.bright_white(),
+            match (&collection.name, &collection.metadata) {
+                (Some(name), _) => name.bright_white().bold(),
+                (None, Some(metadata)) => metadata.dimmed(),
+                (None, None) => "No metadata".dimmed(),
+            }
+        );
+    }
+
+    output::emit(json!({
+        "account": account.to_string(),
+        "collections": collections
+            .iter()
+            .map(|collection| json!({
+                "collection_id": collection.collection_id,
+                "roles": collection.roles,
+                "items": collection.items,
+                "max_supply": collection.max_supply,
+                "metadata": collection.metadata,
+                "name": collection.name,
+            }))
+            .collect::<Vec<_>>(),
+    }));
+    Ok(())
+}
+
+fn u32_at(bytes: &[u8], start: usize) -> u32 {
+    u32::from_le_bytes(bytes[start..start + 4].try_into().expect("four bytes"))
+}
+
+async fn fetch_collection(collection_id: u32, roles: Vec<&'static str>) -> Result<Option<ListedCollection>> {
+    let api = get_client().await?;
+    let storage = api.storage().at_latest().await?;
+    let Some(details) = storage.fetch(&assethub::storage().nfts().collection(collection_id)).await? else {
+        return Ok(None);
+    };
+    let config = storage.fetch(&assethub::storage().nfts().collection_config_of(collection_id)).await?;
+    let metadata = storage
+        .fetch(&assethub::storage().nfts().collection_metadata_of(collection_id))
+        .await?
+        .map(|metadata| String::from_utf8_lossy(&metadata.data.0).into_owned());
+    let name = match &metadata {
+        Some(metadata) => ipfs_utils::metadata_name(metadata).await,
+        None => None,
+    };
+    Ok(Some(ListedCollection {
+        collection_id,
+        roles,
+        items: details.items,
+        max_supply: config.and_then(|config| config.max_supply),
+        metadata,
+        name,
+    }))
+}
@@ -0,0 +1,119 @@
+use crate::client::get_client;
+use crate::commands::assethub;
+use crate::error::{Error, Result};
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::account_utils::parse_address;
+use crate::utils::ipfs_utils;
+use colored::*;
+use serde_json::json;
+use subxt::utils::AccountId32;
+use tokio::task::JoinSet;
+
+/// Number of items whose metadata is fetched at the same time.
+const METADATA_REQUESTS: usize = 16;
+
+/// An item owned by the account, with its on-chain metadata and the name it resolves to.
+struct OwnedNft {
+    collection_id: u32,
+    item_id: u32,
+    metadata: Option<String>,
+    name: Option<String>,
+}
+
+pub async fn list_nfts(address: Option<String>, collection: Option<u32>) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let owner: AccountId32 = match address {
+        Some(address) => parse_address(&address)?,
+        None => crate::config::load_address_from_config()?,
+    };
+
+    let mut sp = Spinner::new(Spinners::Dots12, "⏳ Retrieving owned NFTs...".yellow().bold().to_string());
+    let storage = api.storage().at_latest().await?;
+    let mut ids = Vec::new();
+    match collection {
+        Some(collection) => {
+            let mut entries = storage.iter(assethub::storage().nfts().account_iter2(owner.clone(), collection)).await?;
+            while let Some(entry) = entries.next().await {
+                ids.push(item_key(&entry?.key_bytes));
+            }
+        }
+        None => {
+            let mut entries = storage.iter(assethub::storage().nfts().account_iter1(owner.clone())).await?;
+            while let Some(entry) = entries.next().await {
+                ids.push(item_key(&entry?.key_bytes));
+            }
+        }
+    }
+    ids.sort_unstable();
+
+    let mut nfts = Vec::with_capacity(ids.len());
+    for chunk in ids.chunks(METADATA_REQUESTS) {
+        let mut requests = JoinSet::new();
+        for &(collection_id, item_id) in chunk {
+            requests.spawn(fetch_nft(collection_id, item_id));
+        }
+        while let Some(nft) = requests.join_next().await {
+            nfts.push(nft.map_err(|e| Error::Other(format!("Metadata request failed: {}", e)))??);
+        }
+    }
+    nfts.sort_unstable_by_key(|nft| (nft.collection_id, nft.item_id));
+    sp.stop_and_persist("✅", "Owned NFTs retrieved!".green().bold().to_string());
+
+    say!("\n{} {}\n", "🖼️ NFTs owned by".blue().bold(), owner.to_string().bright_white());
+    if nfts.is_empty() {
+        say!("{}", "No NFTs found.".dimmed());
+    }
+    for nft in &nfts {
+        say!(
+            "{}: {} | {}: {} | {}",
+            "📦 Collection".cyan().bold(),
+            nft.collection_id.to_string().bright_white(),
+            "🎨 NFT".cyan().bold(),
+            nft.item_id.to_string().bright_white(),
+            match (&nft.name, &nft.metadata) {
+                (Some(name), _) => name.bright_white().bold(),
+                (None, Some(metadata)) => metadata.dimmed(),
+                (None, None) => "No metadata".dimmed(),
+            }
+        );
+    }
+
+    output::emit(json!({
+        "owner": owner.to_string(),
+        "nfts": nfts
+            .iter()
+            .map(|nft| json!({
+                "collection_id": nft.collection_id,
+                "item_id": nft.item_id,
+                "metadata": nft.metadata,
+                "name": nft.name,
+            }))
+            .collect::<Vec<_>>(),
+    }));
+    Ok(())
+}
+
+// The collection and item IDs at the end of an `Nfts::Account` key, each hashed with Blake2_128Concat.
+fn item_key(key_bytes: &[u8]) -> (u32, u32) {
+    let id = |end: usize| u32::from_le_bytes(key_bytes[end - 4..end].try_into().expect("four bytes"));
+    (id(key_bytes.len() - 20), id(key_bytes.len()))
+}
+
+async fn fetch_nft(collection_id: u32, item_id: u32) -> Result<OwnedNft> {
+    let api = get_client().await?;
+    let query = assethub::storage().nfts().item_metadata_of(collection_id, item_id);
+    let metadata = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch(&query)
+        .await?
+        .map(|metadata| String::from_utf8_lossy(&metadata.data.0).into_owned());
+    let name = match &metadata {
+        Some(metadata) => ipfs_utils::metadata_name(metadata).await,
+        None => None,
+    };
+    Ok(OwnedNft { collection_id, item_id, metadata, name })
+}
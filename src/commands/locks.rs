@@ -0,0 +1,228 @@
+use crate::cli::MintSettingsArgs;
+use crate::client::{get_client, get_token_info};
+use crate::commands::assethub;
+use crate::error::{Error, Result};
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::settings_utils::{self, CollectionLock, OrNone};
+use crate::utils::tx_utils;
+use clap::ValueEnum;
+use colored::*;
+use pallet_nfts::CollectionSetting;
+use serde_json::json;
+
+/// Lock the transfers of an NFT, which needs the Freezer role.
+pub async fn freeze_nft(collection_id: u32, nft_id: u32) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().lock_item_transfer(collection_id, nft_id);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🧊 Freezing NFT...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "NFT frozen! It cannot be transferred until thawed.".green().bold().to_string());
+
+    extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::ItemTransferLocked>()?
+        .ok_or("ItemTransferLocked event not found")?;
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["item_id"] = json!(nft_id);
+    output::emit(result);
+    Ok(())
+}
+
+/// Unlock the transfers of a frozen NFT, which needs the Freezer role.
+pub async fn thaw_nft(collection_id: u32, nft_id: u32) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().unlock_item_transfer(collection_id, nft_id);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🔥 Thawing NFT...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "NFT thawed! It can be transferred again.".green().bold().to_string());
+
+    extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::ItemTransferUnlocked>()?
+        .ok_or("ItemTransferUnlocked event not found")?;
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["item_id"] = json!(nft_id);
+    output::emit(result);
+    Ok(())
+}
+
+/// Lock the metadata and/or attributes of an NFT for good, which needs the Admin role.
+pub async fn lock_nft(collection_id: u32, nft_id: u32, metadata: bool, attributes: bool) -> Result<()> {
+    if !metadata && !attributes {
+        return Err(Error::invalid_input("Give --metadata, --attributes or both."));
+    }
+
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx()
+        .nfts()
+        .lock_item_properties(collection_id, nft_id, metadata, attributes);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🔒 Locking NFT properties...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "NFT properties locked!".green().bold().to_string());
+
+    let assethub::nfts::events::ItemPropertiesLocked { collection, item, lock_metadata, lock_attributes } = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::ItemPropertiesLocked>()?
+        .ok_or("ItemPropertiesLocked event not found")?;
+
+    let locked: Vec<&str> = [(lock_metadata, "metadata"), (lock_attributes, "attributes")]
+        .into_iter()
+        .filter(|(lock, _)| *lock)
+        .map(|(_, name)| name)
+        .collect();
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection.to_string().bright_white());
+    say!("{}: {}", "🎨 NFT ID".cyan().bold(), item.to_string().bright_white());
+    say!("{}: {}", "🔒 Locked".cyan().bold(), locked.join(", ").bright_white());
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection);
+    result["item_id"] = json!(item);
+    result["locked"] = json!(locked);
+    output::emit(result);
+    Ok(())
+}
+
+/// Lock collection settings for good, which needs the Freezer role.
+pub async fn lock_collection(collection_id: u32, locks: Vec<CollectionLock>) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let config = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch(&assethub::storage().nfts().collection_config_of(collection_id))
+        .await?
+        .ok_or_else(|| Error::invalid_input(format!("Collection {} not found.", collection_id)))?;
+    // Settings that are already locked stay locked. `DepositRequired` is not a lock, and the pallet
+    // refuses it here.
+    let current = config.settings.0 & !(CollectionSetting::DepositRequired as u64);
+    let lock_settings = settings_utils::collection_settings(current, &locks, &[]);
+    let locked = settings_utils::collection_locks(lock_settings.0);
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().lock_collection(collection_id, lock_settings);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🔒 Locking collection settings...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Collection settings locked!".green().bold().to_string());
+
+    extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::CollectionLocked>()?
+        .ok_or("CollectionLocked event not found")?;
+
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection_id.to_string().bright_white());
+    say!("{}: {}", "🔒 Locked".cyan().bold(), locked.join(", ").bright_white());
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["locked"] = json!(locked);
+    output::emit(result);
+    Ok(())
+}
+
+/// Overwrite the configuration of a collection, including locked settings, with the force origin.
+///
+/// Options left out keep their current value.
+pub async fn force_collection_config(
+    collection_id: u32,
+    max_supply: Option<OrNone<u32>>,
+    lock: Vec<CollectionLock>,
+    unlock: Vec<CollectionLock>,
+    mint: MintSettingsArgs,
+) -> Result<()> {
+    if let Some(setting) = lock.iter().find(|setting| unlock.contains(setting)).and_then(|setting| setting.to_possible_value()) {
+        return Err(Error::invalid_input(format!("`{}` cannot be both locked and unlocked.", setting.get_name())));
+    }
+
+    let api = get_client().await?;
+    let token = get_token_info().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let config_query = assethub::storage().nfts().collection_config_of(collection_id);
+    let mut config = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch(&config_query)
+        .await?
+        .ok_or_else(|| Error::invalid_input(format!("Collection {} not found.", collection_id)))?;
+    if let Some(OrNone(max_supply)) = max_supply {
+        config.max_supply = max_supply;
+    }
+    config.settings = settings_utils::collection_settings(config.settings.0, &lock, &unlock);
+    config.mint_settings = settings_utils::mint_settings(&mint, Some(config.mint_settings), token)?;
+    let config_json = settings_utils::collection_config_to_json(&config);
+
+    say!("\n{}\n", "⚙️ New Collection Settings".blue().bold());
+    settings_utils::print_collection_config(&config, token);
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().force_collection_config(collection_id, config);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🚀 Sending transaction to the network...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Collection configuration replaced!".green().bold().to_string());
+
+    extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::CollectionConfigChanged>()?
+        .ok_or("CollectionConfigChanged event not found")?;
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["config"] = config_json;
+    output::emit(result);
+    Ok(())
+}
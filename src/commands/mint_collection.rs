@@ -1,130 +1,393 @@
-use crate::error::Result;
-use crate::utils::{json_utils};
+use crate::error::{Error, Result};
+use crate::output::{self, Spinner, Spinners};
+use crate::signer::AccountSigner;
+use crate::utils::manifest_utils::{Journal, JournalEntry, Manifest, ManifestItem};
+use crate::utils::pipeline_utils;
+use crate::utils::settings_utils::{self, CollectionConfig};
+use crate::utils::tx_utils::{self, Batch, TxResult};
 use colored::*;
-use spinners::{Spinner, Spinners};
+use serde_json::json;
 use subxt::utils::{AccountId32, MultiAddress};
 use crate::commands::assethub;
 use crate::commands::mint_nft;
-use crate::client::get_client;
-use pallet_nfts::{ItemSettings, CollectionSettings};
-use std::marker::PhantomData;
-use std::path::{Path};
-
-// Function to convert CollectionSettings to the required BitFlags1 type
-fn to_collection_bitflags(
-    settings: CollectionSettings,
-) -> assethub::runtime_types::pallet_nfts::types::BitFlags1<
-    assethub::runtime_types::pallet_nfts::types::CollectionSetting,
-> {
-    let bits = settings.0.bits();
-    assethub::runtime_types::pallet_nfts::types::BitFlags1(bits, PhantomData)
-}
+use crate::client::{get_client, get_token_info};
+use crate::cli::MintCollectionArgs;
+use crate::commands::set_collection_metadata::set_collection_metadata_link;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+pub async fn mint_collection(args: MintCollectionArgs) -> Result<()> {
+    let json_folder = args.json.as_deref();
+    let manifest_path = args.manifest.as_deref();
+    let manifest = match (manifest_path, json_folder) {
+        (Some(manifest_path), _) => Some(Manifest::load(Path::new(manifest_path))?),
+        (None, Some(json_folder)) => Some(Manifest::from_folder(Path::new(json_folder), args.image.as_deref().map(Path::new))?),
+        (None, None) => None,
+    };
+    if args.resume && manifest.is_none() {
+        return Err(Error::invalid_input("--resume needs the --manifest or --json folder of the interrupted run."));
+    }
 
-pub async fn mint_collection(json_folder: Option<&str>, image_folder: Option<&str>) -> Result<()> {
     let api = get_client().await?;
-    println!("{}", "🚀 Connection with parachain established.".green().bold());
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+    let token = get_token_info().await?;
+    let config = settings_utils::collection_config(args.max_supply, &args.lock_collection, &args.mint_settings, token)?;
 
-    let account_signer = crate::config::load_account_from_config()?;
-    let admin: MultiAddress<AccountId32, ()> = account_signer.public_key().into();
+    let journal_path = args
+        .journal
+        .as_deref()
+        .map(PathBuf::from)
+        .or_else(|| manifest_path.or(json_folder).map(default_journal_path));
+    let mut journal = match journal_path {
+        Some(path) if args.resume => Journal::resume(&path)?,
+        Some(path) if manifest.is_some() && !tx_utils::is_dry_run() && !tx_utils::is_build() => {
+            say!("{} {}", "📒 Recording progress in".cyan().bold(), path.display());
+            Journal::create(&path)?
+        }
+        _ => Journal::in_memory(),
+    };
+
+    let account_signer = crate::config::load_signer_from_config()?;
 
-    let config = assethub::runtime_types::pallet_nfts::types::CollectionConfig {
-        settings: to_collection_bitflags(CollectionSettings::all_enabled()),
-        max_supply: None,
-        mint_settings: assethub::runtime_types::pallet_nfts::types::MintSettings {
-            mint_type: assethub::runtime_types::pallet_nfts::types::MintType::Issuer,
-            price: None,
-            start_block: None,
-            end_block: None,
-            default_item_settings: mint_nft::to_item_bitflags(ItemSettings::all_enabled()),
-            __ignore: Default::default(),
-        },
-        __ignore: Default::default(),
+    // Pin the collection metadata first, so that a pinning failure leaves no empty collection behind.
+    let collection_metadata = match &args.collection_json {
+        Some(json_path) if journal.collection_metadata_tx.is_none() => {
+            let pinned = mint_nft::pin_json_metadata(Path::new(json_path), args.collection_image.as_deref().map(Path::new)).await?;
+            Some(pinned.metadata)
+        }
+        _ => None,
     };
 
-    let payload = assethub::tx().nfts().create(admin.clone(), config);
+    let mut result = json!({});
+    let collection = match journal.collection_id {
+        Some(collection) => {
+            say!("{} {}", "⏭️ Resuming into collection".cyan().bold(), collection.to_string().bright_white());
+            collection
+        }
+        None => {
+            let Some(extrinsic_result) = create_collection(config, &account_signer).await? else {
+                return Ok(());
+            };
+            result = extrinsic_result.to_json(&api.metadata())?;
+
+            let Some(assethub::nfts::events::Created { collection, .. }) =
+                extrinsic_result.events.find_first::<assethub::nfts::events::Created>()?
+            else {
+                say!("{}", "❌ Collection ID not found in events.".red().bold());
+                output::emit(result);
+                return Ok(());
+            };
+            say!("\n{}\n", "🎉 Collection Created Successfully!".blue().bold());
+            say!(
+                "{}: {}",
+                "📦 Collection ID".cyan().bold(),
+                collection.to_string().bright_white()
+            );
+            say!(
+                "{}: {}",
+                "🔗 Extrinsic Hash".cyan().bold(),
+                format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+            );
+            journal.record(JournalEntry::Collection {
+                collection_id: collection,
+                extrinsic_hash: format!("{:?}", extrinsic_result.extrinsic_hash()),
+            })?;
+            collection
+        }
+    };
+    result["collection_id"] = json!(collection);
+
+    if let Some(metadata) = collection_metadata {
+        let Some(extrinsic_result) = set_collection_metadata_link(collection, metadata.clone(), &account_signer).await? else {
+            return Ok(());
+        };
+        say!("{}: {}", "📄 Collection Metadata".cyan().bold(), metadata.bright_white());
+        journal.record(JournalEntry::CollectionMetadataSet {
+            metadata: metadata.clone(),
+            extrinsic_hash: format!("{:?}", extrinsic_result.extrinsic_hash()),
+        })?;
+        result["collection_metadata"] = json!(metadata);
+    }
+
+    // Step 2: Mint the NFTs of the manifest into the collection, in order of their item IDs
+    let manifest_items = manifest.as_ref().map_or(&[][..], |manifest| manifest.items.as_slice());
+    if args.resume {
+        record_items_on_chain(collection, manifest_items, &mut journal).await?;
+    }
+    let finished = if args.no_batch {
+        mint_items(collection, manifest_items, &account_signer, &mut journal).await?
+    } else {
+        mint_items_batched(collection, manifest_items, &account_signer, &mut journal, args.concurrency).await?
+    };
+    if !finished {
+        return Ok(());
+    }
+
+    let items: Vec<_> = manifest_items
+        .iter()
+        .map(|item| {
+            let mut item_result = json!(journal.items.get(&item.item_id));
+            item_result["item_id"] = json!(item.item_id);
+            item_result
+        })
+        .collect();
+    result["items"] = json!(items);
+    output::emit(result);
+    Ok(())
+}
+
+async fn create_collection(config: CollectionConfig, account_signer: &AccountSigner) -> Result<Option<TxResult>> {
+    let admin: MultiAddress<AccountId32, ()> = account_signer.public_key().into();
+
+    let payload = assethub::tx().nfts().create(admin, config);
 
     let mut sp = Spinner::new(Spinners::Dots12, "⏳ Preparing transaction...".yellow().bold().to_string());
     sp.stop_and_persist("🚀", "Sending transaction to the network...".yellow().bold().to_string());
 
-    let extrinsic_result = api.tx().sign_and_submit_then_watch_default(&payload, &account_signer).await?;
-
     let mut sp = Spinner::new(Spinners::Dots12, "⏳ Finalizing transaction...".yellow().bold().to_string());
-    let extrinsic_result = extrinsic_result.wait_for_finalized_success().await?;
+    let Some(extrinsic_result) = tx_utils::submit(&payload, account_signer).await? else {
+        return Ok(None);
+    };
 
     sp.stop_and_persist("✅", "Collection creation finalized!".green().bold().to_string());
+    Ok(Some(extrinsic_result))
+}
 
-    let extrinsic_hash = extrinsic_result.extrinsic_hash();
+// The journal only holds what was confirmed, but a batch that was lost track of may have been
+// included after all. Record the steps found done on chain, so that they are not submitted again.
+async fn record_items_on_chain(collection: u32, items: &[ManifestItem], journal: &mut Journal) -> Result<()> {
+    let api = get_client().await?;
+    let storage = api.storage().at_latest().await?;
+    // Keys end with the item ID after its Blake2_128Concat hash.
+    let item_id = |key_bytes: &[u8]| u32::from_le_bytes(key_bytes[key_bytes.len() - 4..].try_into().expect("four bytes"));
 
-    let created_event = extrinsic_result.find_first::<assethub::nfts::events::Created>()?;
+    let mut minted = HashSet::new();
+    let mut entries = storage.iter(assethub::storage().nfts().item_iter1(collection)).await?;
+    while let Some(entry) = entries.next().await {
+        minted.insert(item_id(&entry?.key_bytes));
+    }
+    let mut metadata = HashMap::new();
+    let mut entries = storage.iter(assethub::storage().nfts().item_metadata_of_iter1(collection)).await?;
+    while let Some(entry) = entries.next().await {
+        let entry = entry?;
+        metadata.insert(item_id(&entry.key_bytes), entry.value.data.0);
+    }
 
-    if let Some(event) = created_event {
-        if let assethub::nfts::events::Created { collection, .. } = event {
-            println!("\n{}\n", "🎉 Collection Created Successfully!".blue().bold());
-            println!(
-                "{}: {}",
-                "📦 Collection ID".cyan().bold(),
-                collection.to_string().bright_white()
-            );
+    for item in items {
+        let progress = journal.items.get(&item.item_id);
+        let found_minted = !progress.is_some_and(|progress| progress.minted) && minted.contains(&item.item_id);
+        // Only the metadata this run pinned counts; any other is replaced.
+        let found_metadata = progress.is_some_and(|progress| {
+            !progress.metadata_set
+                && progress
+                    .metadata
+                    .as_ref()
+                    .is_some_and(|link| metadata.get(&item.item_id).is_some_and(|data| *data == link.as_bytes()))
+        });
+        if found_minted || found_metadata {
+            say!("{} {}", "🔎 Found on chain, not minting again item".dimmed(), item.item_id);
+            journal.record(JournalEntry::FoundOnChain { item_id: item.item_id, minted: found_minted, metadata_set: found_metadata })?;
+        }
+    }
+    Ok(())
+}
 
-            // Step 2: Mint NFTs into the collection if JSON and image paths were provided
-            if let Some(json_folder_str) = json_folder {
-                let json_folder_path = Path::new(json_folder_str);
-                let image_folder_path = image_folder.map(Path::new);
-
-                let entries: Vec<_> = json_folder_path
-                    .read_dir()?
-                    .collect::<std::result::Result<Vec<_>, _>>()?;
-
-                for (nft_id, entry) in entries.iter().enumerate() {
-                    let json_path = entry.path();
-
-                    let image_path = if let Some(image_folder_path) = image_folder_path {
-                        json_utils::find_image_for_json(&json_path).ok().or_else(|| {
-                            let file_stem = json_path.file_stem()?.to_str()?;
-                            let image_path = image_folder_path.join(format!("{}.jpg", file_stem));
-                            if image_path.exists() {
-                                Some(image_path)
-                            } else {
-                                let image_path = image_folder_path.join(format!("{}.jpeg", file_stem));
-                                if image_path.exists() {
-                                    Some(image_path)
-                                } else {
-                                    let image_path = image_folder_path.join(format!("{}.png", file_stem));
-                                    image_path.exists().then_some(image_path)
-                                }
-                            }
-                        })
-                    } else {
-                        None
-                    };
-
-                    if image_folder_path.is_some() && image_path.is_none() {
-                        return Err("Error: No image found or provided for the NFT.".into());
-                    }
-
-                    let image_path_str = match image_path {
-                        Some(p) => p.to_str()
-                            .map(|s| s.to_owned())
-                            .ok_or_else(|| "Failed to convert image path to string".to_string())
-                            .map(Some),
-                        None => Ok(None),
-                    }?;
-
-                    let nft_id = nft_id as u32;
-                    mint_nft::mint_nft(collection, nft_id, Some(json_path.to_str().unwrap()), image_path_str.as_deref()).await?;
-                }
-            }
+// Whether the item was minted already, or `None` if the journal records it as done.
+fn pending_mint(item: &ManifestItem, journal: &Journal) -> Option<bool> {
+    let progress = journal.items.get(&item.item_id);
+    let minted = progress.is_some_and(|progress| progress.minted);
+    let metadata_set = progress.is_some_and(|progress| progress.metadata_set);
+    if minted && (metadata_set || item.json.is_none()) {
+        say!("{} {}", "⏭️ Already minted, skipping item".dimmed(), item.item_id);
+        return None;
+    }
+    Some(minted)
+}
+
+// The link to the item's JSON metadata, pinned to IPFS unless an earlier run already did.
+async fn pin_manifest_item(item: &ManifestItem, journal: &mut Journal) -> Result<Option<String>> {
+    if let Some(metadata) = journal.items.get(&item.item_id).and_then(|progress| progress.metadata.clone()) {
+        return Ok(Some(metadata));
+    }
+    let Some(pinned) = mint_nft::pin_item_metadata(item.json.as_deref(), item.image.as_deref()).await? else {
+        return Ok(None);
+    };
+    journal.record(JournalEntry::Pinned {
+        item_id: item.item_id,
+        image: pinned.image,
+        metadata: pinned.metadata.clone(),
+    })?;
+    Ok(Some(pinned.metadata))
+}
+
+// Mint the items with one extrinsic per call, skipping the steps the journal records as done.
+// Returns false if the run has to stop because nothing is broadcast (`--dry-run`, `tx build`).
+async fn mint_items(
+    collection: u32,
+    items: &[ManifestItem],
+    account_signer: &AccountSigner,
+    journal: &mut Journal,
+) -> Result<bool> {
+    for item in items {
+        let Some(minted) = pending_mint(item, journal) else {
+            continue;
+        };
+
+        say!("\n{} {}", "🎨 Minting item".cyan().bold(), item.item_id.to_string().bright_white());
+        let metadata = pin_manifest_item(item, journal).await?;
+
+        if !minted {
+            let Some(extrinsic_result) = mint_nft::mint_item(collection, item.item_id, account_signer).await? else {
+                return Ok(false);
+            };
+            journal.record(JournalEntry::Minted {
+                item_id: item.item_id,
+                extrinsic_hash: format!("{:?}", extrinsic_result.extrinsic_hash()),
+            })?;
+        }
+
+        if let Some(metadata) = metadata {
+            let Some(extrinsic_result) = mint_nft::set_item_metadata(collection, item.item_id, metadata, account_signer).await? else {
+                return Ok(false);
+            };
+            journal.record(JournalEntry::MetadataSet {
+                item_id: item.item_id,
+                extrinsic_hash: format!("{:?}", extrinsic_result.extrinsic_hash()),
+            })?;
         }
-    } else {
-        println!("{}", "❌ Collection ID not found in events.".red().bold());
     }
+    Ok(true)
+}
+
+// An item waiting in a batch, with the calls it contributed.
+struct BatchedItem {
+    item_id: u32,
+    mint: bool,
+    set_metadata: bool,
+}
+
+// Mint the items and set their metadata with `Utility::batch_all` extrinsics, each holding as
+// many items as fit into one extrinsic, with up to `concurrency` of them in flight at once.
+// Returns false like `mint_items`.
+async fn mint_items_batched(
+    collection: u32,
+    items: &[ManifestItem],
+    account_signer: &AccountSigner,
+    journal: &mut Journal,
+    concurrency: usize,
+) -> Result<bool> {
+    let owner: MultiAddress<AccountId32, ()> = account_signer.public_key().into();
+    let mut batch = Batch::new().await?;
+    let mut batches = Vec::new();
+    let mut batched = Vec::new();
 
-    println!(
-        "{}: {}",
-        "🔗 Extrinsic Hash".cyan().bold(),
-        format!("{:?}", extrinsic_hash).bright_white()
+    for item in items {
+        // Only the first batch is simulated or built, so there is no need to pin the others.
+        if !batches.is_empty() && (tx_utils::is_dry_run() || tx_utils::is_build()) {
+            break;
+        }
+        let Some(minted) = pending_mint(item, journal) else {
+            continue;
+        };
+
+        say!("{} {}", "🎨 Preparing item".cyan().bold(), item.item_id.to_string().bright_white());
+        let metadata = pin_manifest_item(item, journal).await?;
+        let batched_item = BatchedItem { item_id: item.item_id, mint: !minted, set_metadata: metadata.is_some() };
+        let calls = mint_nft::mint_calls(collection, item.item_id, owner.clone(), !minted, metadata);
+
+        if !batch.try_push(calls.clone()).await? {
+            batches.push((batch.take(), std::mem::take(&mut batched)));
+            batch.try_push(calls).await?;
+        }
+        batched.push(batched_item);
+    }
+    if !batch.is_empty() {
+        batches.push((batch.take(), batched));
+    }
+    if batches.is_empty() {
+        return Ok(true);
+    }
+
+    let (payloads, contents): (Vec<_>, Vec<_>) = batches.into_iter().unzip();
+    say!(
+        "\n{} {} batches, up to {} at a time...",
+        "🚀 Submitting".yellow().bold(),
+        payloads.len(),
+        concurrency
     );
+    let mut failures = Vec::new();
+    let finished = pipeline_utils::submit_concurrently(&payloads, account_signer, concurrency, |index, result| match result {
+        Ok(extrinsic_result) => record_batch(collection, &extrinsic_result, &contents[index], journal),
+        Err(e) => {
+            say!("{} {}: {}", "❌ Batch failed with items".red().bold(), item_ids(&contents[index]), e);
+            failures.push(e);
+            Ok(())
+        }
+    })
+    .await?;
+
+    if let Some(error) = failures.into_iter().next() {
+        say!(
+            "{}",
+            "⚠️ Some batches failed. Run the command again with --resume to retry their items.".yellow().bold()
+        );
+        return Err(error);
+    }
+    Ok(finished)
+}
+
+// Record the items of a finalized batch, checking them against the events of the extrinsic.
+fn record_batch(collection: u32, extrinsic_result: &TxResult, batched: &[BatchedItem], journal: &mut Journal) -> Result<()> {
+    let mut issued = HashSet::new();
+    for event in extrinsic_result.events.find::<assethub::nfts::events::Issued>() {
+        let event = event?;
+        if event.collection == collection {
+            issued.insert(event.item);
+        }
+    }
+    let mut metadata_set = HashSet::new();
+    for event in extrinsic_result.events.find::<assethub::nfts::events::ItemMetadataSet>() {
+        let event = event?;
+        if event.collection == collection {
+            metadata_set.insert(event.item);
+        }
+    }
 
+    let extrinsic_hash = format!("{:?}", extrinsic_result.extrinsic_hash());
+    for item in batched {
+        if item.mint {
+            if !issued.contains(&item.item_id) {
+                return Err(Error::Other(format!("Item {} was not issued by the batch {}.", item.item_id, extrinsic_hash)));
+            }
+            journal.record(JournalEntry::Minted { item_id: item.item_id, extrinsic_hash: extrinsic_hash.clone() })?;
+        }
+        if item.set_metadata {
+            if !metadata_set.contains(&item.item_id) {
+                return Err(Error::Other(format!("The metadata of item {} was not set by the batch {}.", item.item_id, extrinsic_hash)));
+            }
+            journal.record(JournalEntry::MetadataSet { item_id: item.item_id, extrinsic_hash: extrinsic_hash.clone() })?;
+        }
+    }
+    say!(
+        "{} {} ({})",
+        "✅ Minted items".green().bold(),
+        item_ids(batched).bright_white(),
+        extrinsic_hash
+    );
     Ok(())
 }
 
+fn item_ids(batched: &[BatchedItem]) -> String {
+    match (batched.first(), batched.last()) {
+        (Some(first), Some(last)) if first.item_id != last.item_id => format!("{}–{}", first.item_id, last.item_id),
+        (Some(first), _) => first.item_id.to_string(),
+        _ => String::new(),
+    }
+}
+
+// `items.csv` is journaled in `items.csv.journal.jsonl`, and a folder `metadata/` in `metadata.journal.jsonl`.
+fn default_journal_path(source: &str) -> PathBuf {
+    let source = Path::new(source);
+    let name = source.file_name().and_then(|name| name.to_str()).unwrap_or("mint");
+    source.with_file_name(format!("{}.journal.jsonl", name))
+}
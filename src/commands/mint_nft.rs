@@ -1,137 +1,203 @@
-use crate::error::Result;
-use crate::utils::{ipfs_utils, json_utils};
+use crate::error::{Error, Result};
+use crate::utils::{attribute_utils, ipfs_utils, json_utils};
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::tx_utils::{self, RuntimeCall, TxResult};
+use crate::signer::AccountSigner;
 use colored::*;
-use spinners::{Spinner, Spinners};
 use subxt::utils::{AccountId32, MultiAddress};
 use crate::commands::assethub;
 use crate::client::get_client;
-use pallet_nfts::ItemSettings;
-use std::marker::PhantomData;
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::path::Path;
-use std::path::PathBuf;
-
-// Function to convert ItemSettings to the required BitFlags2 type
-pub fn to_item_bitflags(
-    settings: ItemSettings,
-) -> assethub::runtime_types::pallet_nfts::types::BitFlags2<
-    assethub::runtime_types::pallet_nfts::types::ItemSetting,
-> {
-    let bits = settings.0.bits();
-    assethub::runtime_types::pallet_nfts::types::BitFlags2(bits, PhantomData)
-}
 
-pub async fn mint_nft(collection_id: u32, nft_id: u32, json_path: Option<&str>, image_path: Option<&str>) -> Result<()> {
+pub async fn mint_nft(collection_id: u32, nft_id: u32, json_path: Option<&str>, image_path: Option<&str>, attributes: bool) -> Result<()> {
     let api = get_client().await?;
-    println!("{}", "🚀 Connection with parachain established.".green().bold());
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
 
-    let account_signer = crate::config::load_account_from_config()?;
-    let account: MultiAddress<AccountId32, ()> = account_signer.public_key().into();
+    let account_signer = crate::config::load_signer_from_config()?;
+    // Read the attributes before pinning, so that invalid ones are reported before anything is uploaded.
+    let attribute_calls = match json_path {
+        Some(json_path) if attributes => {
+            attribute_utils::attribute_calls(collection_id, nft_id, &json_utils::load_json_from_file(Path::new(json_path))?)?
+        }
+        _ => Vec::new(),
+    };
+    let pinned = pin_item_metadata(json_path.map(Path::new), image_path.map(Path::new)).await?;
+    let ipfs_json_link = pinned.map(|pinned| pinned.metadata);
+
+    let extrinsic_result = match ipfs_json_link.clone() {
+        // Mint and set the metadata and attributes in one extrinsic, so that the item never exists without them.
+        Some(ipfs_json_link) => {
+            let owner = account_signer.public_key().into();
+            let mut calls = mint_calls(collection_id, nft_id, owner, true, Some(ipfs_json_link));
+            calls.extend(attribute_calls.iter().cloned());
+            let payload = assethub::tx().utility().batch_all(calls);
+            let mut sp = Spinner::new(Spinners::Dots12, "🚀 Sending transaction to the network...".yellow().bold().to_string());
+            let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+                return Ok(());
+            };
+            sp.stop_and_persist("✅", "NFT minted and metadata set!".green().bold().to_string());
+            extrinsic_result
+        }
+        None => match mint_item(collection_id, nft_id, &account_signer).await? {
+            Some(extrinsic_result) => extrinsic_result,
+            None => return Ok(()),
+        },
+    };
 
-    let mut json_data: Option<Value> = None;
-    let mut image_link = String::new();
+    let extrinsic_hash = extrinsic_result.extrinsic_hash();
 
-    if let Some(json_path_str) = json_path {
-        let json_path = Path::new(json_path_str);
-        json_data = Some(json_utils::load_json_from_file(json_path)?);
+    let minted_event = extrinsic_result.events.find_first::<assethub::nfts::events::Issued>()?.ok_or("Minted event not found")?;
 
-        let mut sp = Spinner::new(Spinners::Dots12, "🖼️ Processing image...".yellow().bold().to_string());
+    let assethub::nfts::events::Issued { collection, item, .. } = minted_event;
+    say!("\n{}\n", "🎉 NFT Minted Successfully!".blue().bold());
+    say!(
+        "{}: {}",
+        "📦 Collection ID".cyan().bold(),
+        collection.to_string().bright_white()
+    );
+    say!(
+        "{}: {}",
+        "🎨 NFT ID".cyan().bold(),
+        item.to_string().bright_white()
+    );
 
-        if let Some(image) = json_data.as_ref().unwrap().get("image").and_then(Value::as_str) {
-            if !image.is_empty() {
-                image_link = image.to_string();
-                sp.stop_and_persist("🔗", "Using existing image link in JSON.".green().bold().to_string());
-            }
-        }
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_hash).bright_white()
+    );
 
-        if image_link.is_empty() {
-            let image_path_buf = if let Some(image_path_str) = image_path {
-                PathBuf::from(image_path_str)
-            } else {
-                json_utils::find_image_for_json(json_path)?
-            };
+    if !attribute_calls.is_empty() {
+        say!(
+            "{}: {}",
+            "🏷️ Attributes Set".cyan().bold(),
+            attribute_calls.len().to_string().bright_white()
+        );
+    }
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection);
+    result["item_id"] = json!(item);
+    result["metadata"] = json!(ipfs_json_link);
+    output::emit(result);
+    Ok(())
+}
 
-            let image_bytes = std::fs::read(&image_path_buf)?;
-            image_link = ipfs_utils::pin_to_ipfs(&image_bytes).await?;
-            json_data.as_mut().unwrap()["image"] = Value::String(image_link.clone());
+/// IPFS links of an item's image and JSON metadata.
+pub struct PinnedMetadata {
+    pub image: String,
+    pub metadata: String,
+}
 
-            sp.stop_and_persist("✅", "Image pinned to IPFS and link added to JSON.".green().bold().to_string());
+/// Pin the item's image (unless the JSON already links one) and its JSON metadata to IPFS.
+///
+/// Returns `None` when no JSON metadata is given.
+pub async fn pin_item_metadata(json_path: Option<&Path>, image_path: Option<&Path>) -> Result<Option<PinnedMetadata>> {
+    let Some(json_path) = json_path else {
+        if image_path.is_some() {
+            return Err(Error::invalid_input("--json must be provided when using --image."));
         }
-    } else if image_path.is_some() {
-        return Err("Error: --json must be provided when using --image.".into());
-    }
-
-    let ipfs_json_link = if let Some(json_data) = json_data {
-        let mut sp = Spinner::new(Spinners::Dots12, "📦 Pinning JSON metadata to IPFS...".yellow().bold().to_string());
-        let json_bytes = serde_json::to_vec(&json_data)?;
-        let link = ipfs_utils::pin_to_ipfs(&json_bytes).await?;
-        sp.stop_and_persist("✅", "JSON metadata pinned to IPFS.".green().bold().to_string());
-        Some(link)
-    } else {
-        None
+        return Ok(None);
     };
+    pin_json_metadata(json_path, image_path).await.map(Some)
+}
 
-    if let Some(ref ipfs_json_link) = ipfs_json_link {
-        println!("📄 Pinned JSON to IPFS: {}", ipfs_json_link);
-    }
+/// Pin an image (unless the JSON already links one) and the JSON metadata linking it to IPFS,
+/// for items and collections alike.
+pub async fn pin_json_metadata(json_path: &Path, image_path: Option<&Path>) -> Result<PinnedMetadata> {
+    let mut json_data = json_utils::load_json_from_file(json_path)?;
+    let mut image_link = String::new();
 
-    let witness: Option<assethub::runtime_types::pallet_nfts::types::MintWitness<u32, u128>> = None;
+    let mut sp = Spinner::new(Spinners::Dots12, "🖼️ Processing image...".yellow().bold().to_string());
 
-    let nft_creation_tx = assethub::tx()
-        .nfts()
-        .mint(collection_id, nft_id, account.clone(), witness);
+    if let Some(image) = json_data.get("image").and_then(Value::as_str) {
+        if !image.is_empty() {
+            image_link = image.to_string();
+            sp.stop_and_persist("🔗", "Using existing image link in JSON.".green().bold().to_string());
+        }
+    }
 
-    let mut sp = Spinner::new(Spinners::Dots12, "⏳ Minting NFT...".yellow().bold().to_string());
-    sp.stop();
-    sp = Spinner::new(Spinners::Dots12, "🚀 Sending transaction to the network...".yellow().bold().to_string());
+    if image_link.is_empty() {
+        let image_path_buf = if let Some(image_path) = image_path {
+            image_path.to_path_buf()
+        } else {
+            json_utils::find_image_for_json(json_path)?
+        };
 
-    let extrinsic_result = api
-        .tx()
-        .sign_and_submit_then_watch_default(&nft_creation_tx, &account_signer)
-        .await?;
+        let image_bytes = std::fs::read(&image_path_buf)?;
+        image_link = ipfs_utils::pin_to_ipfs(&image_bytes).await?;
+        json_data["image"] = Value::String(image_link.clone());
 
-    let extrinsic_result = extrinsic_result.wait_for_finalized_success().await?;
-    sp.stop_and_persist("✅", "NFT minting finalized!".green().bold().to_string());
+        sp.stop_and_persist("✅", "Image pinned to IPFS and link added to JSON.".green().bold().to_string());
+    }
 
-    let extrinsic_hash = extrinsic_result.extrinsic_hash();
+    let mut sp = Spinner::new(Spinners::Dots12, "📦 Pinning JSON metadata to IPFS...".yellow().bold().to_string());
+    let json_bytes = serde_json::to_vec(&json_data)?;
+    let link = ipfs_utils::pin_to_ipfs(&json_bytes).await?;
+    sp.stop_and_persist("✅", "JSON metadata pinned to IPFS.".green().bold().to_string());
+    say!("📄 Pinned JSON to IPFS: {}", link);
 
-    if let Some(ipfs_json_link) = ipfs_json_link {
-        let mut sp = Spinner::new(Spinners::Dots12, "📜 Setting NFT metadata...".yellow().bold().to_string());
-        let metadata_link: assethub::runtime_types::bounded_collections::bounded_vec::BoundedVec<u8> = assethub::runtime_types::bounded_collections::bounded_vec::BoundedVec(ipfs_json_link.into_bytes());
+    Ok(PinnedMetadata { image: image_link, metadata: link })
+}
 
-        let nft_metadata_tx = assethub::tx().nfts().set_metadata(collection_id, nft_id, metadata_link);
+/// The calls that mint an item to `owner` (unless `mint` is false because it exists already)
+/// and set its metadata, for packing into a `Utility::batch_all` extrinsic.
+pub fn mint_calls(
+    collection_id: u32,
+    nft_id: u32,
+    owner: MultiAddress<AccountId32, ()>,
+    mint: bool,
+    metadata: Option<String>,
+) -> Vec<RuntimeCall> {
+    use assethub::runtime_types::pallet_nfts::pallet::Call;
+
+    let mut calls = Vec::new();
+    if mint {
+        calls.push(RuntimeCall::Nfts(Call::mint {
+            collection: collection_id,
+            item: nft_id,
+            mint_to: owner,
+            witness_data: None,
+        }));
+    }
+    if let Some(metadata) = metadata {
+        calls.push(RuntimeCall::Nfts(Call::set_metadata {
+            collection: collection_id,
+            item: nft_id,
+            data: assethub::runtime_types::bounded_collections::bounded_vec::BoundedVec(metadata.into_bytes()),
+        }));
+    }
+    calls
+}
 
-        let extrinsic_result = api
-            .tx()
-            .sign_and_submit_then_watch_default(&nft_metadata_tx, &account_signer)
-            .await?;
+/// Mint an item to the signer's account.
+pub async fn mint_item(collection_id: u32, nft_id: u32, account_signer: &AccountSigner) -> Result<Option<TxResult>> {
+    let account: MultiAddress<AccountId32, ()> = account_signer.public_key().into();
+    let witness: Option<assethub::runtime_types::pallet_nfts::types::MintWitness<u32, u128>> = None;
 
-        let extrinsic_result = extrinsic_result.wait_for_finalized_success().await?;
-        sp.stop_and_persist("✅", "Metadata set successfully.".green().bold().to_string());
-    }
+    let nft_creation_tx = assethub::tx()
+        .nfts()
+        .mint(collection_id, nft_id, account, witness);
 
-    let minted_event = extrinsic_result.find_first::<assethub::nfts::events::Issued>()?.ok_or("Minted event not found")?;
+    let mut sp = Spinner::new(Spinners::Dots12, "🚀 Sending transaction to the network...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&nft_creation_tx, account_signer).await? else {
+        return Ok(None);
+    };
+    sp.stop_and_persist("✅", "NFT minting finalized!".green().bold().to_string());
+    Ok(Some(extrinsic_result))
+}
 
-    if let assethub::nfts::events::Issued { collection, item, .. } = minted_event {
-        println!("\n{}\n", "🎉 NFT Minted Successfully!".blue().bold());
-        println!(
-            "{}: {}",
-            "📦 Collection ID".cyan().bold(),
-            collection.to_string().bright_white()
-        );
-        println!(
-            "{}: {}",
-            "🎨 NFT ID".cyan().bold(),
-            item.to_string().bright_white()
-        );
-    }
+/// Set the metadata of an item to a link, usually to its JSON metadata on IPFS.
+pub async fn set_item_metadata(collection_id: u32, nft_id: u32, link: String, account_signer: &AccountSigner) -> Result<Option<TxResult>> {
+    let mut sp = Spinner::new(Spinners::Dots12, "📜 Setting NFT metadata...".yellow().bold().to_string());
+    let metadata_link: assethub::runtime_types::bounded_collections::bounded_vec::BoundedVec<u8> = assethub::runtime_types::bounded_collections::bounded_vec::BoundedVec(link.into_bytes());
 
-    println!(
-        "{}: {}",
-        "🔗 Extrinsic Hash".cyan().bold(),
-        format!("{:?}", extrinsic_hash).bright_white()
-    );
+    let nft_metadata_tx = assethub::tx().nfts().set_metadata(collection_id, nft_id, metadata_link);
 
-    Ok(())
+    let Some(metadata_result) = tx_utils::submit(&nft_metadata_tx, account_signer).await? else {
+        return Ok(None);
+    };
+    sp.stop_and_persist("✅", "Metadata set successfully.".green().bold().to_string());
+    Ok(Some(metadata_result))
 }
-
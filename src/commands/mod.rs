@@ -1,44 +1,122 @@
 // commands/mod.rs
 
-use crate::cli::Commands;
+use crate::cli::{AccountCommands, Commands, ConfigCommands, SwapCommands, TxCommands};
 use crate::commands::account::account_info;
-use crate::config::set_account;
-use crate::error::Result;
+use crate::config::{add_account, list_accounts, set_account, use_account};
+use crate::error::{Error, Result};
 use crate::commands::mint_collection::mint_collection;
 use crate::commands::mint_nft::mint_nft;
 use crate::commands::set_nft_metadata::set_nft_metadata;
 use crate::commands::show_nft::show_nft;
-use crate::commands::show_collection::show_collection;
-use crate::config::set_rpc_url;
+use crate::commands::show_collection::{show_collection, ItemPage};
+use crate::config::{config_get, config_set, config_show, config_unset, export_keystore, import_keystore, set_network, set_rpc_url};
 
-#[subxt::subxt(runtime_metadata_path = "metadata.scale")]
+#[subxt::subxt(
+    runtime_metadata_path = "metadata.scale",
+    // Calls are cloned while packing them into `Utility::batch_all` extrinsics.
+    derive_for_type(path = "asset_hub_paseo_runtime::RuntimeCall", derive = "Clone", recursive)
+)]
 pub mod assethub {}
 
 pub mod account;
 pub mod balance;
 pub mod mint_collection;
 pub mod mint_nft;
+pub mod presigned;
+pub mod set_attribute;
+pub mod clear_attribute;
+pub mod update_mint_settings;
+pub mod set_collection_max_supply;
+pub mod set_collection_metadata;
+pub mod clear_collection_metadata;
+pub mod transfer_nft;
+pub mod burn_nft;
+pub mod destroy_collection;
 pub mod show_nft;
 pub mod set_nft_metadata;
 pub mod show_collection;
+pub mod list_collections;
 pub mod send;
+pub mod approvals;
+pub mod locks;
+pub mod set_team;
+pub mod transfer_ownership;
+pub mod set_price;
+pub mod buy_nft;
+pub mod swap;
+pub mod tx;
 #[cfg(feature = "nft")]
 pub mod list_nfts;
 
 pub async fn run_command(cli: Commands) -> Result<()> {
     match cli {
 #[cfg(feature = "nft")]
-        Commands::ListNfts { address } => list_nfts::list_nfts(address).await,
-        Commands::MintCollection { json, image } => mint_collection(json.as_deref(), image.as_deref()).await,
-        Commands::MintNft { collection_id, nft_id, json, image } => mint_nft(collection_id, nft_id, json.as_deref(), image.as_deref()).await,
+        Commands::ListNfts { address, collection } => list_nfts::list_nfts(address, collection).await,
+        Commands::MintCollection(args) => mint_collection(args).await,
+        Commands::MintNft { collection_id, nft_id, json, image, attributes } => mint_nft(collection_id, nft_id, json.as_deref(), image.as_deref(), attributes).await,
+        Commands::PresignMint(args) => presigned::presign_mint(args).await,
+        Commands::RedeemMint { authorization } => presigned::redeem_mint(authorization).await,
+        Commands::PresignAttributes(args) => presigned::presign_attributes(args).await,
+        Commands::RedeemAttributes { authorization } => presigned::redeem_attributes(authorization).await,
+        Commands::SetAttribute { collection_id, key, value, item, namespace } => set_attribute::set_attribute(collection_id, item, namespace, key, value).await,
+        Commands::ClearAttribute { collection_id, key, item, namespace } => clear_attribute::clear_attribute(collection_id, item, namespace, key).await,
+        Commands::UpdateMintSettings { collection_id, mint_settings } => update_mint_settings::update_mint_settings(collection_id, mint_settings).await,
+        Commands::SetCollectionMaxSupply { collection_id, max_supply } => set_collection_max_supply::set_collection_max_supply(collection_id, max_supply).await,
+        Commands::LockCollection { collection_id, locks } => locks::lock_collection(collection_id, locks).await,
+        Commands::ForceCollectionConfig { collection_id, max_supply, lock_collection, unlock_collection, mint_settings } => locks::force_collection_config(collection_id, max_supply, lock_collection, unlock_collection, mint_settings).await,
+        Commands::FreezeNft { collection_id, nft_id } => locks::freeze_nft(collection_id, nft_id).await,
+        Commands::ThawNft { collection_id, nft_id } => locks::thaw_nft(collection_id, nft_id).await,
+        Commands::LockNft { collection_id, nft_id, metadata, attributes } => locks::lock_nft(collection_id, nft_id, metadata, attributes).await,
+        Commands::SetCollectionMetadata { collection_id, json, image } => set_collection_metadata::set_collection_metadata(collection_id, &json, image.as_deref()).await,
+        Commands::ClearCollectionMetadata { collection_id } => clear_collection_metadata::clear_collection_metadata(collection_id).await,
+        Commands::SetTeam { collection_id, issuer, admin, freezer } => set_team::set_team(collection_id, issuer, admin, freezer).await,
+        Commands::TransferOwnership { collection_id, new_owner } => transfer_ownership::transfer_ownership(collection_id, new_owner).await,
+        Commands::AcceptOwnership { collection_id } => transfer_ownership::accept_ownership(collection_id.0).await,
+        Commands::ApproveTransfer { collection_id, nft_id, delegate, duration } => approvals::approve_transfer(collection_id, nft_id, delegate, duration).await,
+        Commands::CancelApproval { collection_id, nft_id, delegate } => approvals::cancel_approval(collection_id, nft_id, delegate).await,
+        Commands::ClearApprovals { collection_id, nft_id } => approvals::clear_approvals(collection_id, nft_id).await,
+        Commands::SetPrice { collection_id, nft_id, price, buyer } => set_price::set_price(collection_id, nft_id, price.0, buyer).await,
+        Commands::BuyNft { collection_id, nft_id, max_price } => buy_nft::buy_nft(collection_id, nft_id, max_price).await,
+        Commands::Swap { command } => match command {
+            SwapCommands::Create { collection_id, nft_id, for_collection, for_nft, pay, ask, duration } => swap::create_swap(collection_id, nft_id, for_collection, for_nft, pay, ask, duration).await,
+            SwapCommands::Claim { collection_id, nft_id, for_collection, for_nft } => swap::claim_swap(collection_id, nft_id, for_collection, for_nft).await,
+            SwapCommands::Cancel { collection_id, nft_id } => swap::cancel_swap(collection_id, nft_id).await,
+        },
+        Commands::TransferNft { collection_id, nft_id, dest } => transfer_nft::transfer_nft(collection_id, nft_id, dest).await,
+        Commands::BurnNft { collection_id, nft_id, yes } => burn_nft::burn_nft(collection_id, nft_id, yes).await,
+        Commands::DestroyCollection { collection_id, burn_items, yes } => destroy_collection::destroy_collection(collection_id, burn_items, yes).await,
         Commands::SetNftMetadata { collection_id, nft_id, json, image } => set_nft_metadata(collection_id, nft_id, json.as_deref(), image.as_deref()).await,
         Commands::ShowNft { collection_id, nft_id, json, image } => show_nft(collection_id, nft_id, json, image).await,
-        Commands::ShowCollection { collection_id } => show_collection(collection_id).await,
+        Commands::ShowCollection { collection_id, items, names, page_size, after } => {
+            let items = items.then_some(ItemPage { names, page_size, after });
+            show_collection(collection_id, items).await
+        }
+        Commands::ListCollections { address } => list_collections::list_collections(address).await,
         Commands::Send { address, amount } => send::send(address, amount).await,
-        Commands::SetAccount { mnemonic, secret_uri } => set_account(mnemonic, secret_uri).await,
+        Commands::SetAccount { mnemonic, secret_uri, name } => set_account(name, mnemonic, secret_uri).await,
+        Commands::ImportKeystore { path, name } => import_keystore(name, path).await,
+        Commands::ExportKeystore { path } => export_keystore(path).await,
         Commands::SetRpc { rpc_url } => set_rpc_url(rpc_url).await,
+        Commands::SetNetwork { network } => set_network(network).await,
         Commands::Balance { address } => balance::balance(address).await,
-        Commands::Account { public_key } => account_info(public_key).await,
+        Commands::Account { command, public_key } => match (command, public_key) {
+            (Some(AccountCommands::Add { name, mnemonic, secret_uri, keystore }), _) => add_account(name, mnemonic, secret_uri, keystore).await,
+            (Some(AccountCommands::List), _) => list_accounts().await,
+            (Some(AccountCommands::Use { name }), _) => use_account(name).await,
+            (None, Some(public_key)) => account_info(public_key).await,
+            (None, None) => Err(Error::invalid_input("Provide a public key or an account subcommand.")),
+        },
+        Commands::Config { command } => match command {
+            ConfigCommands::Get { key } => config_get(key).await,
+            ConfigCommands::Set { key, value } => config_set(key, value).await,
+            ConfigCommands::Unset { key } => config_unset(key).await,
+            ConfigCommands::Show => config_show().await,
+        },
+        Commands::Tx { command } => match command {
+            TxCommands::Build { out, mortality, command } => tx::tx_build(command, out, mortality).await,
+            TxCommands::Sign { path, out } => tx::tx_sign(path, out).await,
+            TxCommands::Submit { extrinsic } => tx::tx_submit(extrinsic).await,
+        },
     }
 }
 
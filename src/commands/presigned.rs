@@ -0,0 +1,473 @@
+use crate::cli::{PresignAttributesArgs, PresignMintArgs};
+use crate::client::{get_client, get_token_info};
+use crate::commands::{assethub, mint_nft};
+use crate::error::{Error, Result};
+use crate::network::TokenInfo;
+use crate::output::{self, Spinner, Spinners};
+use crate::signer;
+use crate::utils::account_utils::parse_address;
+use crate::utils::attribute_utils::{self, NamespaceArg};
+use crate::utils::{json_utils, tx_utils};
+use colored::*;
+use pallet_nfts::CollectionRole;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::path::Path;
+use subxt::ext::codec::{Decode, Encode};
+use subxt::utils::AccountId32;
+use subxt_signer::sr25519::{PublicKey, Signature};
+
+type PreSignedMint = assethub::runtime_types::pallet_nfts::types::PreSignedMint<u32, u32, AccountId32, u32, u128>;
+type PreSignedAttributes = assethub::runtime_types::pallet_nfts::types::PreSignedAttributes<u32, u32, AccountId32, u32>;
+type MultiSignature = assethub::runtime_types::sp_runtime::MultiSignature;
+
+/// A mint signed off-chain by an issuer of the collection, as written by `presign-mint`.
+#[derive(Serialize, Deserialize)]
+pub struct SignedMint {
+    /// Address of the issuer who signed the mint
+    pub signer: String,
+    /// SCALE encoded `PreSignedMint`, as hex
+    pub mint_data: String,
+    /// sr25519 signature of `mint_data`, as hex
+    pub signature: String,
+}
+
+/// Attributes signed off-chain for an item, as written by `presign-attributes`.
+#[derive(Serialize, Deserialize)]
+pub struct SignedAttributes {
+    /// Address of the account who signed the attributes
+    pub signer: String,
+    /// SCALE encoded `PreSignedAttributes`, as hex
+    pub attributes_data: String,
+    /// sr25519 signature of `attributes_data`, as hex
+    pub signature: String,
+}
+
+/// Sign an authorization to mint an item, which any account (or only `--buyer`) can redeem
+/// with `redeem-mint` until its deadline, paying the fee and the price.
+///
+/// Nothing is submitted, but the chain is queried for the current block and the signer's role.
+pub async fn presign_mint(args: PresignMintArgs) -> Result<()> {
+    let api = get_client().await?;
+    let token = get_token_info().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let keypair = crate::config::load_account_from_config()?;
+    let account: AccountId32 = keypair.public_key().into();
+    let storage = api.storage().at_latest().await?;
+    let roles = storage
+        .fetch(&assethub::storage().nfts().collection_role_of(args.collection_id, account.clone()))
+        .await?;
+    if roles.is_none_or(|roles| roles.0 & CollectionRole::Issuer as u8 == 0) {
+        return Err(Error::invalid_input(format!(
+            "{} is not the issuer of collection {}, so its signature would not be accepted.",
+            account, args.collection_id
+        )));
+    }
+    if storage.fetch(&assethub::storage().nfts().item(args.collection_id, args.nft_id)).await?.is_some() {
+        return Err(Error::invalid_input(format!("NFT {} of collection {} already exists.", args.nft_id, args.collection_id)));
+    }
+
+    let max_duration = api.constants().at(&assethub::constants().nfts().max_deadline_duration())?;
+    let duration = args.duration.unwrap_or(max_duration);
+    if duration > max_duration {
+        return Err(Error::invalid_input(format!("The chain accepts a duration of at most {} blocks.", max_duration)));
+    }
+    let only_account = args.buyer.as_deref().map(parse_address).transpose()?;
+
+    // Read the attributes before pinning, so that invalid ones are reported before anything is uploaded.
+    let attributes = match &args.json {
+        Some(json_path) if args.attributes => {
+            attribute_utils::metadata_attributes(&json_utils::load_json_from_file(Path::new(json_path))?)?
+        }
+        _ => Vec::new(),
+    };
+    let pinned = mint_nft::pin_item_metadata(args.json.as_deref().map(Path::new), args.image.as_deref().map(Path::new)).await?;
+
+    let mint_data = PreSignedMint {
+        collection: args.collection_id,
+        item: args.nft_id,
+        attributes: attributes.into_iter().map(|(key, value)| (key.into_bytes(), value.into_bytes())).collect(),
+        metadata: pinned.map(|pinned| pinned.metadata.into_bytes()).unwrap_or_default(),
+        only_account,
+        deadline: api.blocks().at_latest().await?.number() + duration,
+        mint_price: args.price.as_ref().map(|price| token.to_plancks(price)).transpose()?,
+    };
+    let encoded = mint_data.encode();
+    let signed = SignedMint {
+        signer: account.to_string(),
+        mint_data: format!("0x{}", hex::encode(&encoded)),
+        signature: format!("0x{}", hex::encode(keypair.sign(&encoded).0)),
+    };
+
+    say!("\n{}\n", "✍️ Mint Authorization Signed".blue().bold());
+    print_mint_data(&mint_data, &account, token);
+    save_authorization(&signed, args.out.as_deref(), "redeem-mint")?;
+
+    let mut result = mint_data_to_json(&mint_data);
+    result["authorization"] = serde_json::to_value(&signed)?;
+    output::emit(result);
+    Ok(())
+}
+
+/// Mint an item with an authorization from `presign-mint`, signed by the configured account.
+pub async fn redeem_mint(authorization: String) -> Result<()> {
+    let signed: SignedMint = load_authorization(authorization, "presign-mint")?;
+    // What is shown below is what the issuer signed, even if the file was edited by hand.
+    let (signer, encoded, signature) = verify_authorization(&signed.signer, &signed.mint_data, &signed.signature)?;
+    let mint_data = PreSignedMint::decode(&mut encoded.as_slice())
+        .map_err(|e| Error::invalid_input(format!("The mint data of the authorization cannot be decoded: {}", e)))?;
+
+    let api = get_client().await?;
+    let token = get_token_info().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    say!("\n{}\n", "🎟️ Mint Authorization".blue().bold());
+    print_mint_data(&mint_data, &signer, token);
+
+    check_deadline(mint_data.deadline).await?;
+    let account_signer = crate::config::load_signer_from_config()?;
+    if let Some(only_account) = &mint_data.only_account {
+        if *only_account != account_signer.account_id() {
+            return Err(Error::invalid_input(format!("Only {} can redeem this authorization.", only_account)));
+        }
+    }
+
+    let (collection_id, nft_id) = (mint_data.collection, mint_data.item);
+    let payload = assethub::tx().nfts().mint_pre_signed(mint_data, signature, signer);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🚀 Sending transaction to the network...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "NFT minting finalized!".green().bold().to_string());
+
+    let assethub::nfts::events::Issued { collection, item, owner } = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::Issued>()?
+        .ok_or("Minted event not found")?;
+
+    say!("\n{}\n", "🎉 NFT Minted Successfully!".blue().bold());
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection.to_string().bright_white());
+    say!("{}: {}", "🎨 NFT ID".cyan().bold(), item.to_string().bright_white());
+    say!("{}: {}", "👤 Owner".cyan().bold(), owner.to_string().bright_white());
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["item_id"] = json!(nft_id);
+    result["owner"] = json!(owner.to_string());
+    output::emit(result);
+    Ok(())
+}
+
+/// Sign attributes of an item off-chain, which its owner can set with `redeem-attributes` until
+/// the deadline, paying their deposits.
+///
+/// In the collection owner namespace the signer must be an admin of the collection. In the
+/// account namespace the attributes are the signer's own, and redeeming them approves the signer.
+pub async fn presign_attributes(args: PresignAttributesArgs) -> Result<()> {
+    let mut attributes = match &args.json {
+        Some(json_path) => attribute_utils::metadata_attributes(&json_utils::load_json_from_file(Path::new(json_path))?)?,
+        None => Vec::new(),
+    };
+    attributes.extend(args.attributes);
+    if attributes.is_empty() {
+        return Err(Error::invalid_input("There are no attributes to sign; the JSON metadata has none."));
+    }
+
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let keypair = crate::config::load_account_from_config()?;
+    let account: AccountId32 = keypair.public_key().into();
+    let namespace = match args.namespace {
+        NamespaceArg::ItemOwner => {
+            return Err(Error::invalid_input(
+                "Attributes of the item-owner namespace cannot be pre-signed; the owner sets them with `set-attribute`.",
+            ))
+        }
+        namespace => namespace.namespace(account.clone()),
+    };
+    if args.namespace == NamespaceArg::CollectionOwner {
+        let roles = api
+            .storage()
+            .at_latest()
+            .await?
+            .fetch(&assethub::storage().nfts().collection_role_of(args.collection_id, account.clone()))
+            .await?;
+        if roles.is_none_or(|roles| roles.0 & CollectionRole::Admin as u8 == 0) {
+            return Err(Error::invalid_input(format!(
+                "{} is not an admin of collection {}, so its signature would not be accepted.",
+                account, args.collection_id
+            )));
+        }
+    }
+
+    let max_attributes = api.constants().at(&assethub::constants().nfts().max_attributes_per_call())?;
+    if attributes.len() > max_attributes as usize {
+        return Err(Error::invalid_input(format!(
+            "The chain accepts at most {} attributes per authorization, but {} were given.",
+            max_attributes,
+            attributes.len()
+        )));
+    }
+    let max_duration = api.constants().at(&assethub::constants().nfts().max_deadline_duration())?;
+    let duration = args.duration.unwrap_or(max_duration);
+    if duration > max_duration {
+        return Err(Error::invalid_input(format!("The chain accepts a duration of at most {} blocks.", max_duration)));
+    }
+
+    let attributes_data = PreSignedAttributes {
+        collection: args.collection_id,
+        item: args.nft_id,
+        attributes: attributes.into_iter().map(|(key, value)| (key.into_bytes(), value.into_bytes())).collect(),
+        namespace,
+        deadline: api.blocks().at_latest().await?.number() + duration,
+    };
+    let encoded = attributes_data.encode();
+    let signed = SignedAttributes {
+        signer: account.to_string(),
+        attributes_data: format!("0x{}", hex::encode(&encoded)),
+        signature: format!("0x{}", hex::encode(keypair.sign(&encoded).0)),
+    };
+
+    say!("\n{}\n", "✍️ Attributes Authorization Signed".blue().bold());
+    print_attributes_data(&attributes_data, &account);
+    save_authorization(&signed, args.out.as_deref(), "redeem-attributes")?;
+
+    let mut result = attributes_data_to_json(&attributes_data);
+    result["authorization"] = serde_json::to_value(&signed)?;
+    output::emit(result);
+    Ok(())
+}
+
+/// Set the attributes of an item with an authorization from `presign-attributes`, signed by
+/// the configured account, which must own the item.
+pub async fn redeem_attributes(authorization: String) -> Result<()> {
+    let signed: SignedAttributes = load_authorization(authorization, "presign-attributes")?;
+    let (signer, encoded, signature) = verify_authorization(&signed.signer, &signed.attributes_data, &signed.signature)?;
+    let attributes_data = PreSignedAttributes::decode(&mut encoded.as_slice())
+        .map_err(|e| Error::invalid_input(format!("The attributes of the authorization cannot be decoded: {}", e)))?;
+
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    say!("\n{}\n", "🏷️ Attributes Authorization".blue().bold());
+    print_attributes_data(&attributes_data, &signer);
+
+    check_deadline(attributes_data.deadline).await?;
+    let account_signer = crate::config::load_signer_from_config()?;
+    let (collection_id, nft_id) = (attributes_data.collection, attributes_data.item);
+    let item = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch(&assethub::storage().nfts().item(collection_id, nft_id))
+        .await?
+        .ok_or_else(|| Error::invalid_input(format!("NFT {} of collection {} does not exist.", nft_id, collection_id)))?;
+    if item.owner != account_signer.account_id() {
+        return Err(Error::invalid_input(format!("Only the owner of the NFT, {}, can redeem this authorization.", item.owner)));
+    }
+
+    let payload = assethub::tx().nfts().set_attributes_pre_signed(attributes_data, signature, signer);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🚀 Sending transaction to the network...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Attributes set!".green().bold().to_string());
+
+    extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::PreSignedAttributesSet>()?
+        .ok_or("PreSignedAttributesSet event not found")?;
+
+    say!("\n{}\n", "🎉 Attributes Set Successfully!".blue().bold());
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection_id.to_string().bright_white());
+    say!("{}: {}", "🎨 NFT ID".cyan().bold(), nft_id.to_string().bright_white());
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["item_id"] = json!(nft_id);
+    output::emit(result);
+    Ok(())
+}
+
+// Read an authorization written by `command`, from its file or from its JSON content.
+fn load_authorization<T: DeserializeOwned>(authorization: String, command: &str) -> Result<T> {
+    let content = if Path::new(&authorization).is_file() {
+        std::fs::read_to_string(&authorization)?
+    } else {
+        authorization
+    };
+    serde_json::from_str(&content)
+        .map_err(|e| Error::invalid_input(format!("This is not an authorization written by `{}`: {}", command, e)))
+}
+
+// The signer and the signed data of an authorization, once its signature is checked.
+fn verify_authorization(signer: &str, data: &str, signature: &str) -> Result<(AccountId32, Vec<u8>, MultiSignature)> {
+    let signer: AccountId32 = parse_address(signer)?;
+    let encoded = tx_utils::decode_hex(data)?;
+    let signature: [u8; 64] = tx_utils::decode_hex(signature)?
+        .try_into()
+        .map_err(|_| Error::invalid_input("The signature of the authorization must be 64 bytes."))?;
+    if !signer::verify(&PublicKey(signer.0), &encoded, &Signature(signature)) {
+        return Err(Error::invalid_input(format!(
+            "The signature does not match the signed data or was not made by {}. The authorization was altered.",
+            signer
+        )));
+    }
+    let signature = MultiSignature::Sr25519(assethub::runtime_types::sp_core::sr25519::Signature(signature));
+    Ok((signer, encoded, signature))
+}
+
+async fn check_deadline(deadline: u32) -> Result<()> {
+    let block_number = get_client().await?.blocks().at_latest().await?.number();
+    if deadline < block_number {
+        return Err(Error::invalid_input(format!(
+            "The authorization expired at block #{}; the current block is #{}.",
+            deadline, block_number
+        )));
+    }
+    Ok(())
+}
+
+// Print a signed authorization, or save it with `--out` for `redeem_command`.
+fn save_authorization(signed: &impl Serialize, out: Option<&str>, redeem_command: &str) -> Result<()> {
+    match out {
+        Some(out) => {
+            std::fs::write(out, serde_json::to_string_pretty(signed)?)?;
+            say!("\n{} {}", "💾 Authorization saved to".green().bold(), out.bright_white());
+            say!("{}", format!("🎟️ It is redeemed with `polkacli {} {}`.", redeem_command, out).dimmed());
+        }
+        None => {
+            say!("\n{}: {}", "🎟️ Authorization".cyan().bold(), serde_json::to_string(signed)?);
+            say!("{}", format!("Use --out FILE to save it for `polkacli {}`.", redeem_command).dimmed());
+        }
+    }
+    Ok(())
+}
+
+fn print_mint_data(mint_data: &PreSignedMint, signer: &AccountId32, token: &TokenInfo) {
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), mint_data.collection.to_string().bright_white());
+    say!("{}: {}", "🎨 NFT ID".cyan().bold(), mint_data.item.to_string().bright_white());
+    say!("{}: {}", "✍️ Issuer".cyan().bold(), signer.to_string().bright_white());
+    say!(
+        "{}: {}",
+        "💰 Price".cyan().bold(),
+        mint_data.mint_price.map_or("Free".to_string(), |price| token.format_plancks(price)).bright_white()
+    );
+    say!(
+        "{}: {}",
+        "👤 Redeemable By".cyan().bold(),
+        mint_data.only_account.as_ref().map_or("Anyone".to_string(), AccountId32::to_string).bright_white()
+    );
+    say!("{}: #{}", "⏳ Valid Until".cyan().bold(), mint_data.deadline);
+    if !mint_data.metadata.is_empty() {
+        say!("{}: {}", "📄 Metadata".cyan().bold(), String::from_utf8_lossy(&mint_data.metadata).bright_white());
+    }
+    for (key, value) in &mint_data.attributes {
+        say!(
+            "{}: {}",
+            String::from_utf8_lossy(key).cyan().bold(),
+            String::from_utf8_lossy(value).bright_white()
+        );
+    }
+}
+
+fn mint_data_to_json(mint_data: &PreSignedMint) -> Value {
+    json!({
+        "collection_id": mint_data.collection,
+        "item_id": mint_data.item,
+        "price": mint_data.mint_price.map(|price| price.to_string()),
+        "only_account": mint_data.only_account.as_ref().map(AccountId32::to_string),
+        "deadline": mint_data.deadline,
+        "metadata": String::from_utf8_lossy(&mint_data.metadata),
+        "attributes": mint_data
+            .attributes
+            .iter()
+            .map(|(key, value)| json!({ "key": String::from_utf8_lossy(key), "value": String::from_utf8_lossy(value) }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+fn print_attributes_data(attributes_data: &PreSignedAttributes, signer: &AccountId32) {
+    let (namespace, account) = attribute_utils::namespace_parts(&attributes_data.namespace);
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), attributes_data.collection.to_string().bright_white());
+    say!("{}: {}", "🎨 NFT ID".cyan().bold(), attributes_data.item.to_string().bright_white());
+    say!("{}: {}", "✍️ Signer".cyan().bold(), signer.to_string().bright_white());
+    match account {
+        Some(account) => say!("{}: {} ({})", "🏷️ Namespace".cyan().bold(), namespace.bright_white(), account),
+        None => say!("{}: {}", "🏷️ Namespace".cyan().bold(), namespace.bright_white()),
+    }
+    say!("{}: #{}", "⏳ Valid Until".cyan().bold(), attributes_data.deadline);
+    for (key, value) in &attributes_data.attributes {
+        say!(
+            "{}: {}",
+            String::from_utf8_lossy(key).cyan().bold(),
+            String::from_utf8_lossy(value).bright_white()
+        );
+    }
+}
+
+fn attributes_data_to_json(attributes_data: &PreSignedAttributes) -> Value {
+    let (namespace, account) = attribute_utils::namespace_parts(&attributes_data.namespace);
+    json!({
+        "collection_id": attributes_data.collection,
+        "item_id": attributes_data.item,
+        "namespace": namespace,
+        "account": account,
+        "deadline": attributes_data.deadline,
+        "attributes": attributes_data
+            .attributes
+            .iter()
+            .map(|(key, value)| json!({ "key": String::from_utf8_lossy(key), "value": String::from_utf8_lossy(value) }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signer::Keypair;
+    use assethub::runtime_types::pallet_nfts::types::AttributeNamespace;
+    use std::str::FromStr;
+    use subxt_signer::SecretUri;
+
+    #[test]
+    fn verifies_signed_attributes() {
+        let keypair = Keypair::from_uri(&SecretUri::from_str("//Alice").unwrap()).unwrap();
+        let account: AccountId32 = keypair.public_key().into();
+        let attributes_data = PreSignedAttributes {
+            collection: 7,
+            item: 3,
+            attributes: vec![(b"color".to_vec(), b"blue".to_vec())],
+            namespace: AttributeNamespace::Account(account.clone()),
+            deadline: 1_000,
+        };
+        let encoded = attributes_data.encode();
+        let signature = format!("0x{}", hex::encode(keypair.sign(&encoded).0));
+
+        let (signer, data, _) = verify_authorization(&account.to_string(), &format!("0x{}", hex::encode(&encoded)), &signature).unwrap();
+        assert_eq!(signer, account);
+        let decoded = PreSignedAttributes::decode(&mut data.as_slice()).unwrap();
+        assert_eq!(attributes_data_to_json(&decoded), attributes_data_to_json(&attributes_data));
+
+        let mut altered = encoded.clone();
+        *altered.last_mut().unwrap() ^= 1;
+        assert!(verify_authorization(&account.to_string(), &format!("0x{}", hex::encode(&altered)), &signature).is_err());
+        let bob: AccountId32 = subxt_signer::sr25519::dev::bob().public_key().into();
+        assert!(verify_authorization(&bob.to_string(), &format!("0x{}", hex::encode(&encoded)), &signature).is_err());
+    }
+}
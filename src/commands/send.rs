@@ -1,30 +1,33 @@
 use crate::error::Result;
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::tx_utils;
 use colored::*;
-use spinners::{Spinner, Spinners};
+use serde_json::json;
 use subxt::{
     utils::{AccountId32, MultiAddress},
 };
-use std::str::FromStr;
+use crate::utils::account_utils::parse_address;
 use std::time::Duration;
 use tokio::time::sleep;
 use crate::commands::assethub;
-use crate::client::get_client;
+use crate::client::{get_client, get_token_info};
+use crate::network::Amount;
 
-pub async fn send(recipient: String, amount: f64) -> Result<()> {
+pub async fn send(recipient: String, amount: Amount) -> Result<()> {
     // Establish a connection to the parachain
     let api = get_client().await?;
-    println!("{}", "🚀 Connection with parachain established.".green().bold());
+    let token = get_token_info().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
 
     // Parse recipient address
-    let recipient: AccountId32 = AccountId32::from_str(&recipient)?;
+    let recipient: AccountId32 = parse_address(&recipient)?;
 
     // Load sender account from config
-    let account_signer = crate::config::load_account_from_config()?;
+    let account_signer = crate::config::load_signer_from_config()?;
     let from: AccountId32 = account_signer.public_key().into();
 
-    // Convert PAS to Planck (1 PAS = 10^10 Plancks)
-    let plancks_per_pas = 10u128.pow(10) as f64;
-    let amount_in_plancks = (amount * plancks_per_pas).round() as u128;
+    // Convert the amount to plancks using the chain's token decimals
+    let amount_in_plancks = token.to_plancks(&amount)?;
 
     // Create transfer payload
     let payload = assethub::tx().balances().transfer_keep_alive(MultiAddress::Id(recipient.clone()), amount_in_plancks);
@@ -35,15 +38,12 @@ pub async fn send(recipient: String, amount: f64) -> Result<()> {
     sp.stop_and_persist("🚀", "Sending transaction to the network...".yellow().bold().to_string());
 
     // Sign and submit the transaction
-    let extrinsic_result = api
-        .tx()
-        .sign_and_submit_then_watch_default(&payload, &account_signer)
-        .await?;
-
     // Update the spinner for finalization with periodic status updates
     let mut sp = Spinner::new(Spinners::Dots12, "⏳ Finalizing transaction...".yellow().bold().to_string());
 
-    let extrinsic_result = extrinsic_result.wait_for_finalized_success().await?;
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
 
     // Stop the spinner with a final message
     sp.stop_and_persist("✅", "Funds sent successfully!".green().bold().to_string());
@@ -51,28 +51,33 @@ pub async fn send(recipient: String, amount: f64) -> Result<()> {
     let extrinsic_hash = extrinsic_result.extrinsic_hash();
 
     // Output the result
-    println!("\n{}\n", "💸 Transfer Details".blue().bold());
-    println!(
+    say!("\n{}\n", "💸 Transfer Details".blue().bold());
+    say!(
         "{}: {}",
         "📤 From".cyan().bold(),
         from.to_string().bright_white()
     );
-    println!(
+    say!(
         "{}: {}",
         "📥 To".cyan().bold(),
         recipient.to_string().bright_white()
     );
-    println!(
-        "{}: {:.10} PAS",
+    say!(
+        "{}: {}",
         "💰 Amount".cyan().bold(),
-        amount
+        token.format_plancks(amount_in_plancks)
     );
-    println!(
+    say!(
         "{}: {}",
         "🔗 Extrinsic Hash".cyan().bold(),
         format!("{:?}", extrinsic_hash).bright_white()
     );
 
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["from"] = json!(from.to_string());
+    result["to"] = json!(recipient.to_string());
+    result["amount"] = json!(amount_in_plancks.to_string());
+    output::emit(result);
     Ok(())
 }
 
@@ -0,0 +1,40 @@
+use crate::client::get_client;
+use crate::commands::assethub;
+use crate::error::Result;
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::tx_utils;
+use colored::*;
+use serde_json::json;
+
+pub async fn set_collection_max_supply(collection_id: u32, max_supply: u32) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().set_collection_max_supply(collection_id, max_supply);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🚀 Sending transaction to the network...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Max supply set!".green().bold().to_string());
+
+    let assethub::nfts::events::CollectionMaxSupplySet { collection, max_supply } = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::CollectionMaxSupplySet>()?
+        .ok_or("CollectionMaxSupplySet event not found")?;
+
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection.to_string().bright_white());
+    say!("{}: {}", "🔢 Max Supply".cyan().bold(), max_supply.to_string().bright_white());
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection);
+    result["max_supply"] = json!(max_supply);
+    output::emit(result);
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use crate::client::get_client;
+use crate::commands::{assethub, mint_nft};
+use crate::error::Result;
+use crate::output::{self, Spinner, Spinners};
+use crate::signer::AccountSigner;
+use crate::utils::tx_utils::{self, TxResult};
+use colored::*;
+use serde_json::json;
+use std::path::Path;
+
+pub async fn set_collection_metadata(collection_id: u32, json_path: &str, image_path: Option<&str>) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let pinned = mint_nft::pin_json_metadata(Path::new(json_path), image_path.map(Path::new)).await?;
+
+    let Some(extrinsic_result) = set_collection_metadata_link(collection_id, pinned.metadata.clone(), &account_signer).await? else {
+        return Ok(());
+    };
+    let assethub::nfts::events::CollectionMetadataSet { collection, .. } = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::CollectionMetadataSet>()?
+        .ok_or("CollectionMetadataSet event not found")?;
+
+    say!("\n{}\n", "🎉 Collection Metadata Set Successfully!".blue().bold());
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection.to_string().bright_white());
+    say!("{}: {}", "📄 Metadata".cyan().bold(), pinned.metadata.bright_white());
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection);
+    result["image"] = json!(pinned.image);
+    result["metadata"] = json!(pinned.metadata);
+    output::emit(result);
+    Ok(())
+}
+
+/// Set the metadata of a collection to a link, usually to its JSON metadata on IPFS.
+pub async fn set_collection_metadata_link(collection_id: u32, link: String, account_signer: &AccountSigner) -> Result<Option<TxResult>> {
+    let mut sp = Spinner::new(Spinners::Dots12, "📜 Setting collection metadata...".yellow().bold().to_string());
+    let data = assethub::runtime_types::bounded_collections::bounded_vec::BoundedVec(link.into_bytes());
+    let payload = assethub::tx().nfts().set_collection_metadata(collection_id, data);
+
+    let Some(extrinsic_result) = tx_utils::submit(&payload, account_signer).await? else {
+        return Ok(None);
+    };
+    sp.stop_and_persist("✅", "Collection metadata set successfully.".green().bold().to_string());
+    Ok(Some(extrinsic_result))
+}
@@ -1,19 +1,19 @@
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::utils::{ipfs_utils, json_utils};
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::tx_utils;
 use colored::*;
-use spinners::{Spinner, Spinners};
-use subxt::utils::AccountId32;
 use crate::commands::assethub;
 use crate::client::get_client;
 use std::path::Path;
-use serde_json::Value;
+use serde_json::{json, Value};
 use std::path::PathBuf;
 
 pub async fn set_nft_metadata(collection_id: u32, nft_id: u32, json_path: Option<&str>, image_path: Option<&str>) -> Result<()> {
     let api = get_client().await?;
-    println!("{}", "🚀 Connection with parachain established.".green().bold());
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
 
-    let account_signer = crate::config::load_account_from_config()?;
+    let account_signer = crate::config::load_signer_from_config()?;
 
     let mut json_data: Option<Value> = None;
     let mut image_link = String::new();
@@ -48,7 +48,7 @@ pub async fn set_nft_metadata(collection_id: u32, nft_id: u32, json_path: Option
             sp.stop_and_persist("✅", "Image pinned to IPFS and link added to JSON.".green().bold().to_string());
         }
     } else if image_path.is_some() {
-        return Err("Error: --json must be provided when using --image.".into());
+        return Err(Error::invalid_input("--json must be provided when using --image."));
     }
 
     // Step 2: Pin JSON metadata to IPFS
@@ -63,7 +63,7 @@ pub async fn set_nft_metadata(collection_id: u32, nft_id: u32, json_path: Option
     };
 
     if let Some(ref ipfs_json_link) = ipfs_json_link {
-        println!("📄 Pinned JSON to IPFS: {}", ipfs_json_link);
+        say!("📄 Pinned JSON to IPFS: {}", ipfs_json_link);
 
         // Step 3: Set the metadata on-chain for the NFT
         let mut sp = Spinner::new(Spinners::Dots12, "📜 Setting NFT metadata on-chain...".yellow().bold().to_string());
@@ -72,22 +72,26 @@ pub async fn set_nft_metadata(collection_id: u32, nft_id: u32, json_path: Option
 
         let nft_metadata_tx = assethub::tx().nfts().set_metadata(collection_id, nft_id, metadata_link);
 
-        let extrinsic_result = api
-            .tx()
-            .sign_and_submit_then_watch_default(&nft_metadata_tx, &account_signer)
-            .await?;
-
-        let extrinsic_result = extrinsic_result.wait_for_finalized_success().await?;
+        let Some(extrinsic_result) = tx_utils::submit(&nft_metadata_tx, &account_signer).await? else {
+            return Ok(());
+        };
         sp.stop_and_persist("✅", "Metadata set successfully.".green().bold().to_string());
 
         let extrinsic_hash = extrinsic_result.extrinsic_hash();
-        println!(
+        say!(
             "{}: {}",
             "🔗 Extrinsic Hash".cyan().bold(),
             format!("{:?}", extrinsic_hash).bright_white()
         );
+
+        let mut result = extrinsic_result.to_json(&api.metadata())?;
+        result["collection_id"] = json!(collection_id);
+        result["item_id"] = json!(nft_id);
+        result["metadata"] = json!(ipfs_json_link);
+        output::emit(result);
     } else {
-        println!("❌ No valid JSON file found for setting metadata.");
+        say!("❌ No valid JSON file found for setting metadata.");
+        output::emit(json!({ "collection_id": collection_id, "item_id": nft_id, "metadata": null }));
     }
 
     Ok(())
@@ -0,0 +1,66 @@
+use crate::client::{get_client, get_token_info};
+use crate::commands::assethub;
+use crate::error::{Error, Result};
+use crate::network::Amount;
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::account_utils::parse_address;
+use crate::utils::tx_utils;
+use colored::*;
+use serde_json::json;
+use subxt::utils::MultiAddress;
+
+/// List an NFT for sale at `price` in tokens, optionally to a single buyer, or delist it when `price` is `None`.
+pub async fn set_price(collection_id: u32, nft_id: u32, price: Option<Amount>, buyer: Option<String>) -> Result<()> {
+    let api = get_client().await?;
+    let token = get_token_info().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    if price.is_none() && buyer.is_some() {
+        return Err(Error::invalid_input("--buyer needs a price to list the NFT at."));
+    }
+    let price = price.map(|price| token.to_plancks(&price)).transpose()?;
+    let buyer = buyer.map(|buyer| parse_address(&buyer)).transpose()?;
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx()
+        .nfts()
+        .set_price(collection_id, nft_id, price, buyer.clone().map(MultiAddress::Id));
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🏷️ Updating the listing...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+
+    match price {
+        Some(price) => {
+            extrinsic_result
+                .events
+                .find_first::<assethub::nfts::events::ItemPriceSet>()?
+                .ok_or("ItemPriceSet event not found")?;
+            sp.stop_and_persist("✅", "NFT listed for sale!".green().bold().to_string());
+            say!("{}: {}", "💰 Price".cyan().bold(), token.format_plancks(price).bright_white());
+            if let Some(buyer) = &buyer {
+                say!("{}: {}", "👤 Only Buyer".cyan().bold(), buyer.to_string().bright_white());
+            }
+        }
+        None => {
+            extrinsic_result
+                .events
+                .find_first::<assethub::nfts::events::ItemPriceRemoved>()?
+                .ok_or("ItemPriceRemoved event not found")?;
+            sp.stop_and_persist("✅", "NFT delisted!".green().bold().to_string());
+        }
+    }
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["item_id"] = json!(nft_id);
+    result["price"] = json!(price.map(|price| price.to_string()));
+    result["buyer"] = json!(buyer.map(|buyer| buyer.to_string()));
+    output::emit(result);
+    Ok(())
+}
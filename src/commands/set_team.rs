@@ -0,0 +1,79 @@
+use crate::client::get_client;
+use crate::commands::{assethub, show_collection};
+use crate::error::{Error, Result};
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::account_utils::parse_address;
+use crate::utils::settings_utils::OrNone;
+use crate::utils::tx_utils;
+use colored::*;
+use serde_json::json;
+use subxt::utils::{AccountId32, MultiAddress};
+
+/// Change the accounts holding the Issuer, Admin and Freezer roles of a collection.
+///
+/// `set_team` assigns all three roles at once, so roles left out keep their current holder,
+/// and `none` removes a role.
+pub async fn set_team(
+    collection_id: u32,
+    issuer: Option<OrNone<String>>,
+    admin: Option<OrNone<String>>,
+    freezer: Option<OrNone<String>>,
+) -> Result<()> {
+    if issuer.is_none() && admin.is_none() && freezer.is_none() {
+        return Err(Error::invalid_input("Give at least one of --issuer, --admin or --freezer."));
+    }
+
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let current = show_collection::fetch_roles(collection_id).await?;
+    let resolve = |role: &str, arg: Option<OrNone<String>>| -> Result<Option<AccountId32>> {
+        match arg {
+            None => Ok(current.iter().find(|(_, names)| names.contains(&role)).map(|(account, _)| account.clone())),
+            Some(OrNone(None)) => Ok(None),
+            Some(OrNone(Some(address))) => parse_address(&address).map(Some),
+        }
+    };
+    let issuer = resolve("Issuer", issuer)?;
+    let admin = resolve("Admin", admin)?;
+    let freezer = resolve("Freezer", freezer)?;
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().set_team(
+        collection_id,
+        issuer.map(MultiAddress::Id),
+        admin.map(MultiAddress::Id),
+        freezer.map(MultiAddress::Id),
+    );
+
+    let mut sp = Spinner::new(Spinners::Dots12, "👥 Updating the team...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Team updated!".green().bold().to_string());
+
+    let assethub::nfts::events::TeamChanged { collection, issuer, admin, freezer } = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::TeamChanged>()?
+        .ok_or("TeamChanged event not found")?;
+
+    let holder = |account: &Option<AccountId32>| account.as_ref().map(AccountId32::to_string);
+    say!("\n{}\n", "👥 Collection Team".blue().bold());
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection.to_string().bright_white());
+    for (role, account) in [("Issuer", &issuer), ("Admin", &admin), ("Freezer", &freezer)] {
+        say!("{}: {}", role.cyan().bold(), holder(account).unwrap_or_else(|| "None".to_string()).bright_white());
+    }
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection);
+    result["issuer"] = json!(holder(&issuer));
+    result["admin"] = json!(holder(&admin));
+    result["freezer"] = json!(holder(&freezer));
+    output::emit(result);
+    Ok(())
+}
@@ -1,22 +1,49 @@
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::ipfs_utils;
+use crate::utils::settings_utils::{self, item_locks, role_names};
 use colored::*;
-use spinners::{Spinner, Spinners};
+use serde_json::{json, Value};
 use subxt::{
-    utils::AccountId32,
+    utils::{AccountId32, H256},
 };
 use crate::commands::assethub;
 use std::time::Duration;
+use tokio::task::JoinSet;
 use tokio::time::sleep;
-use crate::client::get_client;
+use crate::client::{get_client, get_legacy_rpc, get_token_info};
+
+/// Number of items whose details are fetched at the same time.
+const ITEM_REQUESTS: usize = 16;
+
+/// Which page of a collection's items `show-collection --items` lists.
+pub struct ItemPage {
+    /// Resolve item names from their JSON metadata
+    pub names: bool,
+    pub page_size: u32,
+    /// The last item of the previous page
+    pub after: Option<u32>,
+}
+
+/// An item of the collection with its owner, approvals, locks and metadata.
+struct ListedItem {
+    item_id: u32,
+    owner: AccountId32,
+    approvals: Vec<(AccountId32, Option<u32>)>,
+    locked: Vec<&'static str>,
+    metadata: Option<String>,
+    name: Option<String>,
+}
 
 // Function to convert AccountId32 to SS58 format
 fn format_account_ss58(account_id: &AccountId32) -> String {
     account_id.to_string() // Convert to SS58 if required by your application
 }
 
-pub async fn show_collection(collection_id: u32) -> Result<()> {
+pub async fn show_collection(collection_id: u32, items: Option<ItemPage>) -> Result<()> {
     let api = get_client().await?;
-    println!("{}", "🔍 Fetching Collection information...".green().bold());
+    let token = get_token_info().await?;
+    say!("{}", "🔍 Fetching Collection information...".green().bold());
 
     // Start the spinner
     let mut sp = Spinner::new(Spinners::Dots12, "⏳ Retrieving Collection data...".yellow().bold().to_string());
@@ -28,11 +55,12 @@ pub async fn show_collection(collection_id: u32) -> Result<()> {
         Ok(Some(info)) => Some(info),
         Ok(None) => {
             sp.stop_and_persist("❌", "Collection not found.".red().bold().to_string());
+            output::emit(json!({ "collection_id": collection_id, "collection": null }));
             return Ok(());
         }
         Err(e) => {
             sp.stop_and_persist("❌", format!("Error fetching collection data: {}", e).red().bold().to_string());
-            return Err(Box::new(e));
+            return Err(e.into());
         }
     };
 
@@ -41,37 +69,228 @@ pub async fn show_collection(collection_id: u32) -> Result<()> {
     let metadata_info = match api.storage().at_latest().await?.fetch(&metadata_query).await {
         Ok(Some(metadata)) => Some(metadata),
         Ok(None) => {
-            println!("{}", "❌ Metadata not found.".red().bold());
+            say!("{}", "❌ Metadata not found.".red().bold());
             None
         }
         Err(e) => {
             sp.stop_and_persist("❌", format!("Error fetching metadata: {}", e).red().bold().to_string());
-            return Err(Box::new(e));
+            return Err(e.into());
         }
     };
 
+    // Fetch the configuration of the collection
+    let config_query = assethub::storage().nfts().collection_config_of(collection_id);
+    let config_info = api.storage().at_latest().await?.fetch(&config_query).await?;
+
+    // Fetch the accounts holding roles in the collection
+    let roles = fetch_roles(collection_id).await?;
+
     // Stop the spinner with a final message
     sp.stop_and_persist("✅", "Collection data retrieved!".green().bold().to_string());
 
     // Display the Collection information
-    if let Some(info) = collection_info {
-        println!("\n{}\n", "🏛️ Collection Information".blue().bold());
-        println!("{}: {}", "Collection ID".cyan().bold(), collection_id.to_string().bright_white());
-        println!("{}: {}", "Owner".cyan().bold(), format_account_ss58(&info.owner));
-        println!("{}: {}", "Owner Deposit".cyan().bold(), info.owner_deposit.to_string().bright_white());
-        println!("{}: {}", "Items Count".cyan().bold(), info.items.to_string().bright_white());
-        println!("{}: {}", "Item Metadata Count".cyan().bold(), info.item_metadatas.to_string().bright_white());
-        println!("{}: {}", "Item Config Count".cyan().bold(), info.item_configs.to_string().bright_white());
-        println!("{}: {:?}", "Attributes".cyan().bold(), info.attributes);
+    if let Some(info) = &collection_info {
+        say!("\n{}\n", "🏛️ Collection Information".blue().bold());
+        say!("{}: {}", "Collection ID".cyan().bold(), collection_id.to_string().bright_white());
+        say!("{}: {}", "Owner".cyan().bold(), format_account_ss58(&info.owner));
+        say!("{}: {}", "Owner Deposit".cyan().bold(), token.format_plancks(info.owner_deposit).bright_white());
+        say!("{}: {}", "Items Count".cyan().bold(), info.items.to_string().bright_white());
+        say!("{}: {}", "Item Metadata Count".cyan().bold(), info.item_metadatas.to_string().bright_white());
+        say!("{}: {}", "Item Config Count".cyan().bold(), info.item_configs.to_string().bright_white());
+        say!("{}: {:?}", "Attributes".cyan().bold(), info.attributes);
+    }
+
+    // Display the configuration of the collection
+    if let Some(config) = &config_info {
+        say!("\n{}\n", "⚙️ Collection Settings".blue().bold());
+        settings_utils::print_collection_config(config, token);
+    }
+
+    // Display the team of the collection
+    if !roles.is_empty() {
+        say!("\n{}\n", "👥 Collection Team".blue().bold());
+        for (account, names) in &roles {
+            say!("{}: {}", names.join(", ").cyan().bold(), format_account_ss58(account).bright_white());
+        }
     }
 
     // Display the metadata information
-    if let Some(metadata) = metadata_info {
-        println!("\n{}\n", "📝 Collection Metadata".blue().bold());
-        println!("{}: {:?}", "Data".cyan().bold(), metadata.data);
-        println!("{}: {:?}", "Deposit".cyan().bold(), metadata.deposit);
+    if let Some(metadata) = &metadata_info {
+        say!("\n{}\n", "📝 Collection Metadata".blue().bold());
+        say!("{}: {:?}", "Data".cyan().bold(), metadata.data);
+        say!("{}: {:?}", "Deposit".cyan().bold(), metadata.deposit);
+    }
+
+    let mut result = json!({ "collection_id": collection_id });
+    if let Some(info) = collection_info {
+        result["collection"] = json!({
+            "owner": format_account_ss58(&info.owner),
+            "owner_deposit": info.owner_deposit.to_string(),
+            "items": info.items,
+            "item_metadatas": info.item_metadatas,
+            "item_configs": info.item_configs,
+            "attributes": info.attributes,
+        });
     }
+    result["config"] = config_info.as_ref().map_or(json!(null), settings_utils::collection_config_to_json);
+    result["roles"] = roles
+        .iter()
+        .map(|(account, names)| json!({ "account": format_account_ss58(account), "roles": names }))
+        .collect();
+    result["metadata"] = match metadata_info {
+        Some(metadata) => json!({
+            "data": String::from_utf8_lossy(&metadata.data.0),
+            "deposit": metadata.deposit.to_string(),
+        }),
+        None => json!(null),
+    };
+
+    if let Some(page) = items {
+        let mut sp = Spinner::new(Spinners::Dots12, "⏳ Retrieving items...".yellow().bold().to_string());
+        let (items, next_after) = list_items(collection_id, &page).await?;
+        sp.stop_and_persist("✅", "Items retrieved!".green().bold().to_string());
+        print_items(&items);
+        match next_after {
+            Some(after) => say!(
+                "\n{}",
+                format!("➡️ Next page: polkacli show-collection {} --items --after {}", collection_id, after).dimmed()
+            ),
+            None => say!("\n{}", "No more items.".dimmed()),
+        }
+        result["items"] = items.iter().map(item_to_json).collect();
+        result["next_after"] = json!(next_after);
+    }
+    output::emit(result);
 
     Ok(())
 }
 
+/// The accounts holding roles in a collection, with the roles each of them holds.
+pub async fn fetch_roles(collection_id: u32) -> Result<Vec<(AccountId32, Vec<&'static str>)>> {
+    let api = get_client().await?;
+    let address = assethub::storage().nfts().collection_role_of_iter1(collection_id);
+    let mut entries = api.storage().at_latest().await?.iter(address).await?;
+    let mut roles = Vec::new();
+    while let Some(entry) = entries.next().await {
+        let entry = entry?;
+        // Keys end with the account after its Blake2_128Concat hash.
+        let account: [u8; 32] = entry.key_bytes[entry.key_bytes.len() - 32..].try_into().expect("32 bytes");
+        roles.push((AccountId32(account), role_names(entry.value.0)));
+    }
+    Ok(roles)
+}
+
+// One page of the collection's items, and the item to continue after when more may follow.
+// Items come in the order of their hashed storage keys, so a page is resumed from the key
+// of the last item listed rather than from an item ID.
+async fn list_items(collection_id: u32, page: &ItemPage) -> Result<(Vec<ListedItem>, Option<u32>)> {
+    let api = get_client().await?;
+    let block_hash = api.blocks().at_latest().await?.hash();
+    let prefix = api.storage().address_bytes(&assethub::storage().nfts().item_iter1(collection_id))?;
+    let start_key = page
+        .after
+        .map(|after| api.storage().address_bytes(&assethub::storage().nfts().item(collection_id, after)))
+        .transpose()?;
+    let keys = get_legacy_rpc()
+        .await?
+        .state_get_keys_paged(&prefix, page.page_size, start_key.as_deref(), Some(block_hash))
+        .await?;
+
+    // Keys end with the item ID after its Blake2_128Concat hash.
+    let ids: Vec<u32> = keys
+        .iter()
+        .map(|key| u32::from_le_bytes(key[key.len() - 4..].try_into().expect("four bytes")))
+        .collect();
+    let next_after = if ids.len() == page.page_size as usize { ids.last().copied() } else { None };
+
+    let mut items = Vec::with_capacity(ids.len());
+    for chunk in ids.chunks(ITEM_REQUESTS) {
+        let mut requests = JoinSet::new();
+        for &item_id in chunk {
+            requests.spawn(fetch_item(collection_id, item_id, block_hash, page.names));
+        }
+        while let Some(item) = requests.join_next().await {
+            // An item burned after the keys were read has no details left to show.
+            if let Some(item) = item.map_err(|e| Error::Other(format!("Item request failed: {}", e)))?? {
+                items.push(item);
+            }
+        }
+    }
+    items.sort_unstable_by_key(|item| item.item_id);
+    Ok((items, next_after))
+}
+
+async fn fetch_item(collection_id: u32, item_id: u32, block_hash: H256, names: bool) -> Result<Option<ListedItem>> {
+    let api = get_client().await?;
+    let storage = api.storage().at(block_hash);
+    let Some(details) = storage.fetch(&assethub::storage().nfts().item(collection_id, item_id)).await? else {
+        return Ok(None);
+    };
+    let config = storage.fetch(&assethub::storage().nfts().item_config_of(collection_id, item_id)).await?;
+    let metadata = storage
+        .fetch(&assethub::storage().nfts().item_metadata_of(collection_id, item_id))
+        .await?
+        .map(|metadata| String::from_utf8_lossy(&metadata.data.0).into_owned());
+    let name = match &metadata {
+        Some(metadata) if names => ipfs_utils::metadata_name(metadata).await,
+        _ => None,
+    };
+    Ok(Some(ListedItem {
+        item_id,
+        owner: details.owner,
+        approvals: details.approvals.0,
+        locked: config.map(|config| item_locks(config.settings.0)).unwrap_or_default(),
+        metadata,
+        name,
+    }))
+}
+
+fn print_items(items: &[ListedItem]) {
+    say!("\n{}\n", "🎨 Items".blue().bold());
+    if items.is_empty() {
+        say!("{}", "No items found.".dimmed());
+    }
+    for item in items {
+        say!(
+            "{}: {} | {}: {} | {}",
+            "NFT ID".cyan().bold(),
+            item.item_id.to_string().bright_white(),
+            "Owner".cyan().bold(),
+            format_account_ss58(&item.owner).bright_white(),
+            match (&item.name, &item.metadata) {
+                (Some(name), _) => name.bright_white().bold(),
+                (None, Some(metadata)) => metadata.dimmed(),
+                (None, None) => "No metadata".dimmed(),
+            }
+        );
+        if !item.approvals.is_empty() {
+            let approvals: Vec<String> = item
+                .approvals
+                .iter()
+                .map(|(delegate, deadline)| match deadline {
+                    Some(deadline) => format!("{} (until #{})", format_account_ss58(delegate), deadline),
+                    None => format_account_ss58(delegate),
+                })
+                .collect();
+            say!("    {}: {}", "✅ Approved".cyan(), approvals.join(", "));
+        }
+        if !item.locked.is_empty() {
+            say!("    {}: {}", "🔒 Locked".cyan(), item.locked.join(", "));
+        }
+    }
+}
+
+fn item_to_json(item: &ListedItem) -> Value {
+    json!({
+        "item_id": item.item_id,
+        "owner": format_account_ss58(&item.owner),
+        "approvals": item
+            .approvals
+            .iter()
+            .map(|(delegate, deadline)| json!({ "delegate": format_account_ss58(delegate), "deadline": deadline }))
+            .collect::<Vec<_>>(),
+        "locked": item.locked,
+        "metadata": item.metadata,
+        "name": item.name,
+    })
+}
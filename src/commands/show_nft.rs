@@ -1,22 +1,21 @@
 use crate::error::Result;
+use crate::output::{self, Spinner, Spinners};
 use colored::*;
-use spinners::{Spinner, Spinners};
 use subxt::utils::AccountId32;
-use crate::commands::assethub;
+use crate::commands::{approvals, assethub, swap};
 use std::time::Duration;
 use tokio::time::sleep;
-use crate::client::get_client;
+use crate::client::{get_client, get_token_info};
+use crate::network::TokenInfo;
 use viuer::{print_from_file, Config};
 use reqwest::get;
 use std::path::Path;
-use serde_json::Value;
+use serde_json::{json, Value};
+use crate::utils::attribute_utils;
+use crate::utils::settings_utils::{collection_locks, item_locks};
+use crate::utils::ipfs_utils::{ipfs_to_http_url, is_ipfs_link};
 
 
-// Function to convert Plancks to PAS
-fn plancks_to_pas(amount: u128) -> f64 {
-    (amount as f64) / 10f64.powi(10)
-}
-
 fn format_account_ss58(account_id: &AccountId32) -> String {
     account_id.to_string() // Modify if you need SS58 formatting
 }
@@ -28,17 +27,15 @@ fn format_account_option(account_id: &Option<AccountId32>) -> String {
     }
 }
 
-fn format_item_deposit(deposit: &assethub::runtime_types::pallet_nfts::types::ItemDeposit<u128, AccountId32>) -> String {
+fn format_item_deposit(deposit: &assethub::runtime_types::pallet_nfts::types::ItemDeposit<u128, AccountId32>, token: &TokenInfo) -> String {
     let account = format_account_ss58(&deposit.account);
-    let pas_amount = plancks_to_pas(deposit.amount); // Accessing DepositBalance (u128)
-    let formatted_amount = format!("{:.4} PAS", pas_amount); // Format to 4 decimal places
+    let formatted_amount = token.format_plancks(deposit.amount);
     format!("{}: {} | {}: {}", "Account".cyan().bold(), account.bright_white(), "Amount".cyan().bold(), formatted_amount.bright_white())
 }
 
-fn format_metadata_deposit(deposit: &assethub::runtime_types::pallet_nfts::types::ItemMetadataDeposit<u128, AccountId32>) -> String {
+fn format_metadata_deposit(deposit: &assethub::runtime_types::pallet_nfts::types::ItemMetadataDeposit<u128, AccountId32>, token: &TokenInfo) -> String {
     let account = format_account_option(&deposit.account);
-    let pas_amount = plancks_to_pas(deposit.amount); // Accessing DepositBalance (u128)
-    let formatted_amount = format!("{:.4} PAS", pas_amount); // Format to 4 decimal places
+    let formatted_amount = token.format_plancks(deposit.amount);
     format!("{}: {} | {}: {}", "Account".cyan().bold(), account.bright_white(), "Amount".cyan().bold(), formatted_amount.bright_white())
 }
 
@@ -50,16 +47,6 @@ fn parse_metadata_data(data: &assethub::runtime_types::bounded_collections::boun
     }
 }
 
-// Check if the data is an IPFS link
-fn is_ipfs_link(data: &str) -> bool {
-    data.starts_with("ipfs://")
-}
-
-// Convert an IPFS link to an HTTP URL
-fn ipfs_to_http_url(ipfs_link: &str) -> String {
-    ipfs_link.replace("ipfs://", "https://ipfs.io/ipfs/")
-}
-
 // Download content from a URL
 async fn download_content(url: &str) -> Result<String> {
     let response = get(url).await?;
@@ -78,19 +65,19 @@ async fn download_image(url: &str) -> Result<String> {
 }
 
 // Handle and display JSON data from IPFS
-async fn handle_ipfs_json(json_content: String) -> Result<()> {
+async fn handle_ipfs_json(json_content: String) -> Result<Value> {
     let parsed_json: Value = serde_json::from_str(&json_content)?;
 
-    println!("\n{}\n", "📄 Parsed JSON Metadata".blue().bold());
-    println!("{}", serde_json::to_string_pretty(&parsed_json)?.cyan());
+    say!("\n{}\n", "📄 Parsed JSON Metadata".blue().bold());
+    say!("{}", serde_json::to_string_pretty(&parsed_json)?.cyan());
 
-    Ok(())
+    Ok(parsed_json)
 }
 
 // Function to handle displaying the image from IPFS
 async fn handle_ipfs_image(image_link: &str) -> Result<()> {
     let image_url = ipfs_to_http_url(image_link);
-    println!("📦 Image URL: {}", image_url);
+    say!("📦 Image URL: {}", image_url);
 
     match download_image(&image_url).await {
         Ok(image_path) => {
@@ -98,7 +85,7 @@ async fn handle_ipfs_image(image_link: &str) -> Result<()> {
             print_from_file(Path::new(&image_path), &config).expect("Image display failed");
             std::fs::remove_file(image_path)?;
         }
-        Err(err) => println!("{}", format!("❌ Failed to download image: {}", err).red().bold()),
+        Err(err) => say!("{}", format!("❌ Failed to download image: {}", err).red().bold()),
     }
 
     Ok(())
@@ -106,59 +93,149 @@ async fn handle_ipfs_image(image_link: &str) -> Result<()> {
 
 pub async fn show_nft(collection_id: u32, nft_id: u32, show_json: bool, show_image: bool) -> Result<()> {
     let api = get_client().await?;
-    println!("{}", "🔍 Fetching NFT information...".green().bold());
+    let token = get_token_info().await?;
+    say!("{}", "🔍 Fetching NFT information...".green().bold());
 
     // Start the spinner
     let mut sp = Spinner::new(Spinners::Dots12, "⏳ Retrieving NFT data...".yellow().bold().to_string());
     sleep(Duration::from_secs(1)).await;
 
     // Use the API to query the NFT data based on `collection_id` and `nft_id`
+    let block = api.blocks().at_latest().await?;
     let storage_query = assethub::storage().nfts().item(collection_id, nft_id);
-    let nft_info = api.storage().at_latest().await?.fetch(&storage_query).await?;
+    let nft_info = api.storage().at(block.reference()).fetch(&storage_query).await?;
+
+    // Fetch the settings of the NFT and of its collection, which decide what is locked
+    let item_config_query = assethub::storage().nfts().item_config_of(collection_id, nft_id);
+    let item_config = api.storage().at(block.reference()).fetch(&item_config_query).await?;
+    let collection_config_query = assethub::storage().nfts().collection_config_of(collection_id);
+    let collection_config = api.storage().at(block.reference()).fetch(&collection_config_query).await?;
 
     // Fetch the metadata associated with the NFT
     let metadata_query = assethub::storage().nfts().item_metadata_of(collection_id, nft_id);
     let metadata_info = api.storage().at_latest().await?.fetch(&metadata_query).await?;
 
+    // Fetch the on-chain attributes of the NFT in every namespace
+    let attributes = attribute_utils::fetch_item_attributes(collection_id, nft_id).await?;
+
+    // Fetch the sale listing and the swap offered for the NFT
+    let price_query = assethub::storage().nfts().item_price_of(collection_id, nft_id);
+    let price_info = api.storage().at_latest().await?.fetch(&price_query).await?;
+    let swap_query = assethub::storage().nfts().pending_swap_of(collection_id, nft_id);
+    let swap_info = api.storage().at_latest().await?.fetch(&swap_query).await?;
+
     // Stop the spinner with a final message
     sp.stop_and_persist("✅", "NFT data retrieved!".green().bold().to_string());
+    let mut result = json!({ "collection_id": collection_id, "item_id": nft_id, "item": null, "metadata": null });
+
     // Display the NFT information
     if let Some(info) = nft_info {
-        println!("\n{}\n", "🎨 NFT Information".blue().bold());
-        println!("{}: {}", "Collection ID".cyan().bold(), collection_id.to_string().bright_white());
-        println!("{}: {}", "NFT ID".cyan().bold(), nft_id.to_string().bright_white());
-        println!("{}: {}", "Owner".cyan().bold(), format_account_ss58(&info.owner));
-        //println!("{}: {:?}", "Approvals".cyan().bold(), info.approvals);
-        println!("{}: {}", "Deposit".cyan().bold(), format_item_deposit(&info.deposit));
+        say!("\n{}\n", "🎨 NFT Information".blue().bold());
+        say!("{}: {}", "Collection ID".cyan().bold(), collection_id.to_string().bright_white());
+        say!("{}: {}", "NFT ID".cyan().bold(), nft_id.to_string().bright_white());
+        say!("{}: {}", "Owner".cyan().bold(), format_account_ss58(&info.owner));
+        say!("{}: {}", "Deposit".cyan().bold(), format_item_deposit(&info.deposit, token));
+        let approvals = &info.approvals.0;
+        if !approvals.is_empty() {
+            say!("\n{}\n", "🤝 Transfer Approvals".blue().bold());
+            for (delegate, deadline) in approvals {
+                let expiry = approvals::describe_deadline(*deadline, block.number());
+                let expiry = if deadline.is_some_and(|deadline| deadline < block.number()) { expiry.red() } else { expiry.dimmed() };
+                say!("{}: {} {}", "Delegate".cyan().bold(), format_account_ss58(delegate).bright_white(), expiry);
+            }
+        }
+
+        // Transfers are also locked by the collection, metadata and attributes only by the item.
+        let locked = item_config.map(|config| item_locks(config.settings.0)).unwrap_or_default();
+        let collection_locked = collection_config.map(|config| collection_locks(config.settings.0)).unwrap_or_default();
+        let lock_state = |name: &str| if locked.contains(&name) { "Locked".red() } else { "Unlocked".green() };
+        say!("\n{}\n", "🔒 NFT Locks".blue().bold());
+        let transfers = if locked.contains(&"transfer") {
+            "Frozen".red()
+        } else if collection_locked.contains(&"transfers") {
+            "Locked by the collection".red()
+        } else {
+            "Allowed".green()
+        };
+        say!("{}: {}", "Transfers".cyan().bold(), transfers);
+        say!("{}: {}", "Metadata".cyan().bold(), lock_state("metadata"));
+        say!("{}: {}", "Attributes".cyan().bold(), lock_state("attributes"));
+
+        result["item"] = json!({
+            "owner": format_account_ss58(&info.owner),
+            "deposit": { "account": format_account_ss58(&info.deposit.account), "amount": info.deposit.amount.to_string() },
+            "approvals": approvals::approvals_to_json(approvals, block.number()),
+            "locked": locked,
+            "collection_locked": collection_locked,
+        });
     } else {
-        println!("{}", "❌ NFT not found.".red().bold());
+        say!("{}", "❌ NFT not found.".red().bold());
+    }
+
+    // Display the sale listing and the pending swap
+    if price_info.is_some() || swap_info.is_some() {
+        say!("\n{}\n", "🛒 NFT Market".blue().bold());
+    }
+    result["price"] = json!(null);
+    if let Some((price, buyer)) = &price_info {
+        say!("{}: {}", "For Sale".cyan().bold(), token.format_plancks(*price).bright_white());
+        if let Some(buyer) = buyer {
+            say!("{}: {}", "Only Buyer".cyan().bold(), format_account_ss58(buyer));
+        }
+        result["price"] = json!({ "amount": price.to_string(), "buyer": buyer.as_ref().map(format_account_ss58) });
+    }
+    result["swap"] = json!(null);
+    if let Some(pending) = &swap_info {
+        say!(
+            "{}: {} | {}: {} | {}: #{}",
+            "Swap For".cyan().bold(),
+            swap::describe_desired(pending.desired_collection, pending.desired_item).bright_white(),
+            "Payment".cyan().bold(),
+            swap::describe_price(&pending.price, token).bright_white(),
+            "Deadline".cyan().bold(),
+            pending.deadline
+        );
+        result["swap"] = json!({
+            "desired_collection": pending.desired_collection,
+            "desired_item": pending.desired_item,
+            "price": swap::price_to_json(&pending.price),
+            "deadline": pending.deadline,
+        });
     }
 
     // Display the metadata information
     if let Some(metadata) = metadata_info {
         let metadata_str = parse_metadata_data(&metadata.data);
-        println!("\n{}\n", "📝 NFT Metadata".blue().bold());
-        println!("{}: {}", "Data".cyan().bold(), metadata_str);
-        println!("{}: {}", "Deposit".cyan().bold(), format_metadata_deposit(&metadata.deposit));
+        say!("\n{}\n", "📝 NFT Metadata".blue().bold());
+        say!("{}: {}", "Data".cyan().bold(), metadata_str);
+        say!("{}: {}", "Deposit".cyan().bold(), format_metadata_deposit(&metadata.deposit, token));
+        result["metadata"] = json!({
+            "data": metadata_str,
+            "deposit": {
+                "account": metadata.deposit.account.as_ref().map(format_account_ss58),
+                "amount": metadata.deposit.amount.to_string(),
+            },
+        });
 
         if is_ipfs_link(&metadata_str) {
             let metadata_url = ipfs_to_http_url(&metadata_str);
-            println!("📦 Metadata URL: {}", metadata_url);
+            say!("📦 Metadata URL: {}", metadata_url);
 
             if show_json {
                 match download_content(&metadata_url).await {
                     Ok(content) => {
-                        if let Ok(_) = serde_json::from_str::<Value>(&content) {
-                            handle_ipfs_json(content).await?;
+                        if serde_json::from_str::<Value>(&content).is_ok() {
+                            result["metadata"]["json"] = handle_ipfs_json(content).await?;
                         } else {
-                            println!("Raw Content: {}", content.cyan());
+                            say!("Raw Content: {}", content.cyan());
                         }
                     }
-                    Err(err) => println!("{}", format!("❌ Failed to download metadata: {}", err).red().bold()),
+                    Err(err) => say!("{}", format!("❌ Failed to download metadata: {}", err).red().bold()),
                 }
             }
 
-            if show_image {
+            // Images are rendered to the terminal, which has no JSON equivalent.
+            if show_image && !output::is_json() {
                 match download_content(&metadata_url).await {
                     Ok(content) => {
                         if let Ok(parsed_json) = serde_json::from_str::<Value>(&content) {
@@ -167,14 +244,34 @@ pub async fn show_nft(collection_id: u32, nft_id: u32, show_json: bool, show_ima
                             }
                         }
                     }
-                    Err(err) => println!("{}", format!("❌ Failed to download image: {}", err).red().bold()),
+                    Err(err) => say!("{}", format!("❌ Failed to download image: {}", err).red().bold()),
                 }
             }
         }
     } else {
-        println!("{}", "❌ Metadata not found.".red().bold());
+        say!("{}", "❌ Metadata not found.".red().bold());
+    }
+
+    // Display the on-chain attributes
+    if !attributes.is_empty() {
+        say!("\n{}\n", "🏷️ NFT Attributes".blue().bold());
+        for attribute in &attributes {
+            let (namespace, account) = attribute_utils::namespace_parts(&attribute.namespace);
+            let namespace = match account {
+                Some(account) => format!("{} {}", namespace, account),
+                None => namespace.to_string(),
+            };
+            say!(
+                "{}: {} {}",
+                String::from_utf8_lossy(&attribute.key).cyan().bold(),
+                String::from_utf8_lossy(&attribute.value).bright_white(),
+                format!("({})", namespace).dimmed()
+            );
+        }
     }
+    result["attributes"] = attributes.iter().map(|attribute| attribute.to_json()).collect();
 
+    output::emit(result);
     Ok(())
 }
 
@@ -0,0 +1,205 @@
+use crate::client::{get_client, get_token_info};
+use crate::commands::assethub;
+use crate::error::{Error, Result};
+use crate::network::{Amount, TokenInfo};
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::tx_utils;
+use colored::*;
+use serde_json::{json, Value};
+
+use assethub::runtime_types::pallet_nfts::types::{PriceDirection, PriceWithDirection};
+
+/// Offer an NFT in exchange for an NFT of another collection, optionally with a payment on top.
+///
+/// `pay` is sent by the offerer along with the NFT, `ask` is requested from whoever claims the swap.
+pub async fn create_swap(
+    collection_id: u32,
+    nft_id: u32,
+    for_collection: u32,
+    for_nft: Option<u32>,
+    pay: Option<Amount>,
+    ask: Option<Amount>,
+    duration: u32,
+) -> Result<()> {
+    let api = get_client().await?;
+    let token = get_token_info().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let price = match (pay, ask) {
+        (Some(pay), _) => Some(PriceWithDirection { amount: token.to_plancks(&pay)?, direction: PriceDirection::Send }),
+        (None, Some(ask)) => Some(PriceWithDirection { amount: token.to_plancks(&ask)?, direction: PriceDirection::Receive }),
+        (None, None) => None,
+    };
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx()
+        .nfts()
+        .create_swap(collection_id, nft_id, for_collection, for_nft, price, duration);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🔁 Creating swap...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Swap created!".green().bold().to_string());
+
+    let assethub::nfts::events::SwapCreated { desired_collection, desired_item, price, deadline, .. } = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::SwapCreated>()?
+        .ok_or("SwapCreated event not found")?;
+
+    say!("\n{}\n", "🔁 Swap Created Successfully!".blue().bold());
+    say!("{}: {}", "📤 Offered".cyan().bold(), format!("NFT {} of collection {}", nft_id, collection_id).bright_white());
+    say!("{}: {}", "📥 Wanted".cyan().bold(), describe_desired(desired_collection, desired_item).bright_white());
+    say!("{}: {}", "💰 Payment".cyan().bold(), describe_price(&price, token).bright_white());
+    say!("{}: #{}", "⏳ Deadline".cyan().bold(), deadline);
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["item_id"] = json!(nft_id);
+    result["desired_collection"] = json!(desired_collection);
+    result["desired_item"] = json!(desired_item);
+    result["price"] = price_to_json(&price);
+    result["deadline"] = json!(deadline);
+    output::emit(result);
+    Ok(())
+}
+
+/// Claim the swap offered for an NFT, giving one of the claimer's NFTs in exchange.
+///
+/// The payment of the pending swap is shown and passed as the witness price, so the claim
+/// fails if the swap is changed before it is included.
+pub async fn claim_swap(collection_id: u32, nft_id: u32, for_collection: u32, for_nft: u32) -> Result<()> {
+    let api = get_client().await?;
+    let token = get_token_info().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let swap_query = assethub::storage().nfts().pending_swap_of(for_collection, for_nft);
+    let swap = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch(&swap_query)
+        .await?
+        .ok_or_else(|| Error::invalid_input(format!("NFT {} of collection {} is not offered in a swap.", for_nft, for_collection)))?;
+    if swap.desired_collection != collection_id || swap.desired_item.is_some_and(|item| item != nft_id) {
+        return Err(Error::invalid_input(format!(
+            "The swap asks for {}, not NFT {} of collection {}.",
+            describe_desired(swap.desired_collection, swap.desired_item),
+            nft_id,
+            collection_id
+        )));
+    }
+    say!("{}: {}", "💰 Payment".cyan().bold(), describe_price(&swap.price, token).bright_white());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx()
+        .nfts()
+        .claim_swap(collection_id, nft_id, for_collection, for_nft, swap.price);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🔁 Claiming swap...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Swap claimed!".green().bold().to_string());
+
+    let claimed = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::SwapClaimed>()?
+        .ok_or("SwapClaimed event not found")?;
+
+    say!("\n{}\n", "🔁 Swap Claimed Successfully!".blue().bold());
+    say!(
+        "{}: {}",
+        "📤 Sent".cyan().bold(),
+        format!("NFT {} of collection {} to {}", claimed.sent_item, claimed.sent_collection, claimed.received_item_owner).bright_white()
+    );
+    say!(
+        "{}: {}",
+        "📥 Received".cyan().bold(),
+        format!("NFT {} of collection {} from {}", claimed.received_item, claimed.received_collection, claimed.sent_item_owner)
+            .bright_white()
+    );
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["sent_collection"] = json!(claimed.sent_collection);
+    result["sent_item"] = json!(claimed.sent_item);
+    result["received_collection"] = json!(claimed.received_collection);
+    result["received_item"] = json!(claimed.received_item);
+    result["price"] = price_to_json(&claimed.price);
+    output::emit(result);
+    Ok(())
+}
+
+/// Withdraw the swap offered for an NFT.
+pub async fn cancel_swap(collection_id: u32, nft_id: u32) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().cancel_swap(collection_id, nft_id);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🔁 Cancelling swap...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Swap cancelled!".green().bold().to_string());
+
+    extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::SwapCancelled>()?
+        .ok_or("SwapCancelled event not found")?;
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["item_id"] = json!(nft_id);
+    output::emit(result);
+    Ok(())
+}
+
+/// What a swap asks for in exchange: a specific NFT, or any NFT of a collection.
+pub fn describe_desired(collection: u32, item: Option<u32>) -> String {
+    match item {
+        Some(item) => format!("NFT {} of collection {}", item, collection),
+        None => format!("any NFT of collection {}", collection),
+    }
+}
+
+/// Who pays what on top of a swap, from the point of view of the offerer.
+pub fn describe_price(price: &Option<PriceWithDirection<u128>>, token: &TokenInfo) -> String {
+    match price {
+        Some(PriceWithDirection { amount, direction: PriceDirection::Send }) => {
+            format!("{} paid by the offerer", token.format_plancks(*amount))
+        }
+        Some(PriceWithDirection { amount, direction: PriceDirection::Receive }) => {
+            format!("{} paid by the claimer", token.format_plancks(*amount))
+        }
+        None => "None".to_string(),
+    }
+}
+
+pub fn price_to_json(price: &Option<PriceWithDirection<u128>>) -> Value {
+    match price {
+        Some(PriceWithDirection { amount, direction }) => json!({
+            "amount": amount.to_string(),
+            "direction": match direction {
+                PriceDirection::Send => "send",
+                PriceDirection::Receive => "receive",
+            },
+        }),
+        None => Value::Null,
+    }
+}
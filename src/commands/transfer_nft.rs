@@ -0,0 +1,52 @@
+use crate::client::get_client;
+use crate::commands::assethub;
+use crate::error::Result;
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::account_utils::parse_address;
+use crate::utils::tx_utils;
+use colored::*;
+use serde_json::json;
+use subxt::utils::{AccountId32, MultiAddress};
+
+pub async fn transfer_nft(collection_id: u32, nft_id: u32, dest: String) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let dest: AccountId32 = parse_address(&dest)?;
+    let account_signer = crate::config::load_signer_from_config()?;
+
+    let payload = assethub::tx()
+        .nfts()
+        .transfer(collection_id, nft_id, MultiAddress::Id(dest));
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🚀 Sending transaction to the network...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "NFT transfer finalized!".green().bold().to_string());
+
+    let transferred_event = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::Transferred>()?
+        .ok_or("Transferred event not found")?;
+    let assethub::nfts::events::Transferred { collection, item, from, to } = transferred_event;
+
+    say!("\n{}\n", "📦 NFT Transferred Successfully!".blue().bold());
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection.to_string().bright_white());
+    say!("{}: {}", "🎨 NFT ID".cyan().bold(), item.to_string().bright_white());
+    say!("{}: {}", "📤 From".cyan().bold(), from.to_string().bright_white());
+    say!("{}: {}", "📥 To".cyan().bold(), to.to_string().bright_white());
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection);
+    result["item_id"] = json!(item);
+    result["from"] = json!(from.to_string());
+    result["to"] = json!(to.to_string());
+    output::emit(result);
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use crate::client::get_client;
+use crate::commands::assethub;
+use crate::error::{Error, Result};
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::account_utils::parse_address;
+use crate::utils::tx_utils;
+use colored::*;
+use serde_json::json;
+use subxt::utils::{AccountId32, MultiAddress};
+
+/// Hand a collection over to a new owner, who must have accepted it with `accept-ownership`.
+pub async fn transfer_ownership(collection_id: u32, new_owner: String) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let new_owner: AccountId32 = parse_address(&new_owner)?;
+    let acceptance_query = assethub::storage().nfts().ownership_acceptance(new_owner.clone());
+    let accepted = api.storage().at_latest().await?.fetch(&acceptance_query).await?;
+    if accepted != Some(collection_id) {
+        return Err(Error::invalid_input(format!(
+            "{} has not accepted ownership of collection {}. They must run `polkacli accept-ownership {}` first.",
+            new_owner, collection_id, collection_id
+        )));
+    }
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx()
+        .nfts()
+        .transfer_ownership(collection_id, MultiAddress::Id(new_owner));
+
+    let mut sp = Spinner::new(Spinners::Dots12, "👑 Transferring ownership...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Ownership transferred!".green().bold().to_string());
+
+    let assethub::nfts::events::OwnerChanged { collection, new_owner } = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::OwnerChanged>()?
+        .ok_or("OwnerChanged event not found")?;
+
+    say!("{}: {}", "📦 Collection ID".cyan().bold(), collection.to_string().bright_white());
+    say!("{}: {}", "👑 New Owner".cyan().bold(), new_owner.to_string().bright_white());
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection);
+    result["new_owner"] = json!(new_owner.to_string());
+    output::emit(result);
+    Ok(())
+}
+
+/// Agree to receive ownership of a collection, or withdraw the agreement when `collection_id` is `None`.
+pub async fn accept_ownership(collection_id: Option<u32>) -> Result<()> {
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().set_accept_ownership(collection_id);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "👑 Updating ownership acceptance...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Ownership acceptance updated!".green().bold().to_string());
+
+    let assethub::nfts::events::OwnershipAcceptanceChanged { who, maybe_collection } = extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::OwnershipAcceptanceChanged>()?
+        .ok_or("OwnershipAcceptanceChanged event not found")?;
+
+    match maybe_collection {
+        Some(collection) => say!(
+            "{} {}",
+            "👑 Ready to receive collection".cyan().bold(),
+            format!("{}. Its owner can now run `polkacli transfer-ownership {} {}`.", collection, collection, who).bright_white()
+        ),
+        None => say!("{}", "👑 No longer accepting any collection.".cyan().bold()),
+    }
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["account"] = json!(who.to_string());
+    result["collection_id"] = json!(maybe_collection);
+    output::emit(result);
+    Ok(())
+}
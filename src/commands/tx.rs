@@ -0,0 +1,170 @@
+use crate::cli::{BuildCommand, Commands};
+use crate::client::get_client;
+use crate::commands::run_command;
+use crate::error::{Error, Result};
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::account_utils::parse_address;
+use crate::utils::json_utils;
+use crate::utils::tx_utils::{self, BuildOptions, UnsignedTx};
+use clap::{error::ErrorKind, Parser};
+use colored::*;
+use serde_json::{json, Value};
+use std::path::Path;
+use subxt::tx::SubmittableExtrinsic;
+use subxt::utils::AccountId32;
+
+/// Run a command that submits an extrinsic, but only build its unsigned transaction.
+pub async fn tx_build(args: Vec<String>, out: Option<String>, mortality: u64) -> Result<()> {
+    let command = match BuildCommand::try_parse_from(args) {
+        Ok(build) => build.command,
+        Err(e) if matches!(e.kind(), ErrorKind::DisplayHelp | ErrorKind::DisplayVersion) => e.exit(),
+        Err(e) => return Err(Error::invalid_input(e.render().to_string().trim_start_matches("error: ").trim_end())),
+    };
+    match command {
+        Commands::Tx { .. } => {
+            return Err(Error::invalid_input("`tx build` takes a command such as `send` or `mint-nft`."));
+        }
+        // These submit several extrinsics, of which only the first would be built.
+        Commands::MintCollection(_) | Commands::DestroyCollection { burn_items: true, .. } => {
+            return Err(Error::invalid_input(
+                "`tx build` covers a single extrinsic, but this command submits several. \
+                 Run it directly, or build its steps one by one with commands such as `mint-nft` and `burn-nft`.",
+            ));
+        }
+        _ => {}
+    }
+
+    tx_utils::enable_build(BuildOptions { mortality });
+    Box::pin(run_command(command)).await?;
+    let unsigned = tx_utils::built()
+        .ok_or_else(|| Error::invalid_input("This command does not submit an extrinsic, so there is nothing to build."))?;
+
+    let api = get_client().await?;
+    let (_, args) = tx_utils::decode_call(&tx_utils::decode_hex(&unsigned.call_data)?, &api.metadata())?;
+    print_unsigned_tx(unsigned, &args);
+
+    match out {
+        Some(out) => {
+            std::fs::write(&out, serde_json::to_string_pretty(unsigned)?)?;
+            say!("\n{} {}", "💾 Unsigned transaction saved to".green().bold(), out.bright_white());
+            say!("{}", format!("✍️ Sign it with `polkacli tx sign {}`.", out).dimmed());
+        }
+        None => say!("\n{}", "ℹ️ Use --out FILE or --output json to save the transaction for `tx sign`.".dimmed()),
+    }
+    output::emit(serde_json::to_value(unsigned)?);
+    Ok(())
+}
+
+/// Sign a transaction built by `tx build`, without connecting to the network.
+pub async fn tx_sign(path: String, out: Option<String>) -> Result<()> {
+    let unsigned: UnsignedTx = serde_json::from_value(json_utils::load_json_from_file(Path::new(&path))?)
+        .map_err(|e| Error::invalid_input(format!("{} is not a transaction built by `tx build`: {}", path, e)))?;
+
+    let client = unsigned.offline_client()?;
+    let partial = unsigned.partial(&client)?;
+    // Whatever is shown below is what gets signed, even if the file was edited by hand.
+    if format!("0x{}", hex::encode(partial.signer_payload())) != unsigned.signer_payload {
+        return Err(Error::invalid_input(format!(
+            "The signer payload in {} does not match its call data and parameters. \
+             The file was altered or built for a different runtime.",
+            path
+        )));
+    }
+    let (call, args) = tx_utils::decode_call(partial.call_data(), &client.metadata())?;
+    print_unsigned_tx(&UnsignedTx { call, ..unsigned.clone() }, &args);
+
+    let signer: AccountId32 = parse_address(&unsigned.signer)?;
+    let keypair = crate::config::load_account_from_config()?;
+    let account: AccountId32 = keypair.public_key().into();
+    if account != signer {
+        return Err(Error::invalid_input(format!(
+            "The transaction must be signed by {}, but the selected account is {}. Select another account with --from.",
+            signer, account
+        )));
+    }
+
+    let extrinsic = partial.sign(&keypair);
+    let encoded = format!("0x{}", hex::encode(extrinsic.encoded()));
+    say!("\n{}", "✅ Transaction signed!".green().bold());
+    say!("{}: {}", "🔗 Extrinsic Hash".cyan().bold(), format!("{:?}", extrinsic.hash()).bright_white());
+    match &out {
+        Some(out) => {
+            std::fs::write(out, &encoded)?;
+            say!("{} {}", "💾 Signed extrinsic saved to".green().bold(), out.bright_white());
+            say!("{}", format!("🚀 Broadcast it with `polkacli tx submit {}`.", out).dimmed());
+        }
+        None => {
+            say!("{}: {}", "📦 Signed extrinsic".cyan().bold(), encoded);
+            say!("{}", "🚀 Broadcast it with `polkacli tx submit <EXTRINSIC>`.".dimmed());
+        }
+    }
+
+    output::emit(json!({
+        "signer": signer.to_string(),
+        "extrinsic_hash": format!("{:?}", extrinsic.hash()),
+        "extrinsic": encoded,
+    }));
+    Ok(())
+}
+
+/// Broadcast a signed extrinsic, given as hex or as a file containing it, and watch it.
+pub async fn tx_submit(extrinsic: String) -> Result<()> {
+    let encoded = if Path::new(&extrinsic).is_file() {
+        std::fs::read_to_string(&extrinsic)?
+    } else {
+        extrinsic
+    };
+    let bytes = tx_utils::decode_hex(&encoded)?;
+
+    let api = get_client().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+    let extrinsic = SubmittableExtrinsic::from_bytes(api.clone(), bytes);
+
+    if tx_utils::is_dry_run() {
+        return tx_utils::dry_run("signed extrinsic", &extrinsic).await;
+    }
+
+    let mut sp = Spinner::new(Spinners::Dots12, "⏳ Finalizing transaction...".yellow().bold().to_string());
+    let extrinsic_result = tx_utils::wait_for_finalized_success(extrinsic.submit_and_watch().await?).await?;
+    sp.stop_and_persist("✅", "Transaction finalized!".green().bold().to_string());
+
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+    say!(
+        "{}: {}",
+        "📦 Block Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.block_hash).bright_white()
+    );
+
+    output::emit(extrinsic_result.to_json(&api.metadata())?);
+    Ok(())
+}
+
+fn print_unsigned_tx(unsigned: &UnsignedTx, args: &Value) {
+    say!("\n{}\n", "📝 Transaction Details".blue().bold());
+    say!("{}: {}", "📞 Call".cyan().bold(), unsigned.call.bright_white());
+    say!("{}: {}", "📋 Arguments".cyan().bold(), args);
+    say!("{}: {}", "👤 Signer".cyan().bold(), unsigned.signer.bright_white());
+    say!("{}: {}", "🔢 Nonce".cyan().bold(), unsigned.nonce);
+    match &unsigned.mortality {
+        Some(mortality) => say!(
+            "{}: {} blocks from #{} ({})",
+            "⏳ Valid for".cyan().bold(),
+            mortality.period,
+            mortality.block_number,
+            mortality.block_hash
+        ),
+        None => say!("{}: {}", "⏳ Valid for".cyan().bold(), "ever (immortal)"),
+    }
+    say!("{}: {}", "🧬 Genesis Hash".cyan().bold(), unsigned.genesis_hash);
+    say!(
+        "{}: {} (transaction version {})",
+        "📜 Spec Version".cyan().bold(),
+        unsigned.spec_version,
+        unsigned.transaction_version
+    );
+    say!("{}: {}", "✍️ Signer Payload".cyan().bold(), unsigned.signer_payload);
+}
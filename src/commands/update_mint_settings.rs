@@ -0,0 +1,54 @@
+use crate::cli::MintSettingsArgs;
+use crate::client::{get_client, get_token_info};
+use crate::commands::assethub;
+use crate::error::{Error, Result};
+use crate::output::{self, Spinner, Spinners};
+use crate::utils::{settings_utils, tx_utils};
+use colored::*;
+use serde_json::json;
+
+pub async fn update_mint_settings(collection_id: u32, args: MintSettingsArgs) -> Result<()> {
+    let api = get_client().await?;
+    let token = get_token_info().await?;
+    say!("{}", "🚀 Connection with parachain established.".green().bold());
+
+    // Mint settings are replaced as a whole, so start from the current ones.
+    let config_query = assethub::storage().nfts().collection_config_of(collection_id);
+    let current = api
+        .storage()
+        .at_latest()
+        .await?
+        .fetch(&config_query)
+        .await?
+        .ok_or_else(|| Error::invalid_input(format!("Collection {} not found.", collection_id)))?;
+    let mint_settings = settings_utils::mint_settings(&args, Some(current.mint_settings), token)?;
+    let mint_settings_json = settings_utils::mint_settings_to_json(&mint_settings);
+
+    say!("\n{}\n", "🪙 New Mint Settings".blue().bold());
+    settings_utils::print_mint_settings(&mint_settings, token);
+
+    let account_signer = crate::config::load_signer_from_config()?;
+    let payload = assethub::tx().nfts().update_mint_settings(collection_id, mint_settings);
+
+    let mut sp = Spinner::new(Spinners::Dots12, "🚀 Sending transaction to the network...".yellow().bold().to_string());
+    let Some(extrinsic_result) = tx_utils::submit(&payload, &account_signer).await? else {
+        return Ok(());
+    };
+    sp.stop_and_persist("✅", "Mint settings updated!".green().bold().to_string());
+
+    extrinsic_result
+        .events
+        .find_first::<assethub::nfts::events::CollectionMintSettingsUpdated>()?
+        .ok_or("CollectionMintSettingsUpdated event not found")?;
+    say!(
+        "{}: {}",
+        "🔗 Extrinsic Hash".cyan().bold(),
+        format!("{:?}", extrinsic_result.extrinsic_hash()).bright_white()
+    );
+
+    let mut result = extrinsic_result.to_json(&api.metadata())?;
+    result["collection_id"] = json!(collection_id);
+    result["mint_settings"] = mint_settings_json;
+    output::emit(result);
+    Ok(())
+}
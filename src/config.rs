@@ -1,146 +1,652 @@
-use crate::error::Result;
+use crate::error::{Error, Result};
+use crate::keystore::{self, KeystoreJson};
+use crate::network::find_network;
+use crate::output;
+use crate::signer::{AccountSigner, Keypair};
+use crate::utils::{account_utils::parse_address, tx_utils};
+use colored::*;
 use dirs::home_dir;
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 use std::{
+    collections::BTreeMap,
     fs,
-    io::{self, Write},
-    path::PathBuf,
+    io,
+    path::{Path, PathBuf},
     str::FromStr,
 };
-use subxt::{OnlineClient, PolkadotConfig};
-use subxt_signer::{bip39::Mnemonic, sr25519::Keypair, SecretUri};
+use subxt::{utils::AccountId32, OnlineClient, PolkadotConfig};
+use subxt_signer::{bip39::Mnemonic, SecretUri};
 
 const CONFIG_DIR: &str = ".polkacli";
 const CONFIG_FILE: &str = "config";
+const ACCOUNTS_DIR: &str = "accounts";
+const DEFAULT_ACCOUNT: &str = "default";
 
-fn config_file_path() -> PathBuf {
+/// Current schema version of the config file. Files without a version are
+/// the flat `key = "value"` format and are migrated on first load.
+const CONFIG_VERSION: i64 = 1;
+
+// Keys whose values are masked by `config show`.
+const SECRET_KEYS: &[&str] = &["pinata_jwt", "mnemonic", "secret_uri"];
+
+static ACCOUNT_OVERRIDE: OnceCell<String> = OnceCell::new();
+
+/// The contents of `~/.polkacli/config`.
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct Config {
+    pub version: i64,
+    pub defaults: Defaults,
+    pub accounts: BTreeMap<String, AccountConfig>,
+    pub networks: BTreeMap<String, NetworkConfig>,
+    pub ipfs: IpfsConfig,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct Defaults {
+    /// Name of the account used for signing.
+    pub account: Option<String>,
+    /// Name of the network profile to connect to.
+    pub network: Option<String>,
+    /// Custom RPC endpoint, used instead of the network profile's URL.
+    pub rpc_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct AccountConfig {
+    pub address: Option<String>,
+    pub keystore: Option<String>,
+    /// Plaintext secrets carried over from configs written before keystores existed.
+    pub mnemonic: Option<String>,
+    pub secret_uri: Option<String>,
+}
+
+/// A custom network, or overrides for a built-in one.
+#[derive(Serialize, Deserialize, Default, Clone)]
+#[serde(default)]
+pub struct NetworkConfig {
+    pub rpc_url: Option<String>,
+    pub symbol: Option<String>,
+    pub decimals: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct IpfsConfig {
+    pub pinata_jwt: Option<String>,
+}
+
+fn config_dir_path() -> PathBuf {
     home_dir()
         .expect("Unable to find home directory")
         .join(CONFIG_DIR)
-        .join(CONFIG_FILE)
 }
 
-fn read_config_file() -> Result<String> {
-    fs::read_to_string(config_file_path()).map_err(|e| {
-        if e.kind() == io::ErrorKind::NotFound {
-            io::Error::new(io::ErrorKind::NotFound, "Configuration file not found").into()
-        } else {
-            e.into()
-        }
-    })
+fn config_file_path() -> PathBuf {
+    config_dir_path().join(CONFIG_FILE)
 }
 
-fn write_config_file(content: &str) -> Result<()> {
-    let config_path = config_file_path();
-    if let Some(parent) = config_path.parent() {
-        fs::create_dir_all(parent)?;
+/// Load the config file, migrating it from the flat format if needed.
+pub fn load_config() -> Result<Config> {
+    load_config_in(&config_dir_path())
+}
+
+fn load_config_in(dir: &Path) -> Result<Config> {
+    let path = dir.join(CONFIG_FILE);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            return Ok(Config { version: CONFIG_VERSION, ..Default::default() })
+        }
+        Err(e) => return Err(Error::config(format!("Failed to read config file: {}", e))),
+    };
+    let invalid = |e: &dyn std::fmt::Display| Error::config(format!("Invalid config file {}: {}", path.display(), e));
+    let table = content.parse::<toml::Table>().map_err(|e| invalid(&e))?;
+
+    match table.get("version") {
+        Some(toml::Value::Integer(CONFIG_VERSION)) => toml::from_str(&content).map_err(|e| invalid(&e)),
+        Some(toml::Value::Integer(version)) if *version > CONFIG_VERSION => Err(Error::config(format!(
+            "Config file version {} is newer than this polkacli supports ({}).",
+            version, CONFIG_VERSION
+        ))),
+        Some(version) => Err(invalid(&format!("unsupported version {}", version))),
+        // Only the flat format has neither a version nor sections; anything else was edited by hand.
+        None if table.values().any(toml::Value::is_table) => {
+            Err(invalid(&"the `version` key is missing, so the file cannot be read safely"))
+        }
+        None => {
+            let config = migrate_flat_config(&table);
+            let backup = backup_path(dir);
+            fs::copy(&path, &backup)?;
+            save_config_to(&config, &path)?;
+            eprintln!(
+                "{}",
+                format!("🔧 Migrated config file to version {} (backup saved as {}).", CONFIG_VERSION, backup.display())
+                    .yellow()
+                    .bold()
+            );
+            Ok(config)
+        }
     }
+}
 
-    let mut file = fs::OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(config_path)?;
+pub fn save_config(config: &Config) -> Result<()> {
+    save_config_to(config, &config_file_path())
+}
 
-    writeln!(file, "{}", content)?;
+fn save_config_to(config: &Config, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, toml::to_string_pretty(config)?)?;
     Ok(())
 }
 
-fn update_config(key: &str, value: &str) -> Result<()> {
-    let mut config_content = read_config_file().unwrap_or_default();
-
-    let mut updated = false;
-    let new_content = config_content
-        .lines()
-        .map(|line| {
-            if line.starts_with(key) {
-                updated = true;
-                format!("{} = \"{}\"", key, value)
-            } else {
-                line.to_string()
-            }
+// A backup name that is not taken yet, so that earlier backups are never overwritten.
+fn backup_path(dir: &Path) -> PathBuf {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or_default();
+    (0..)
+        .map(|n| match n {
+            0 => dir.join(format!("{}.{}.bak", CONFIG_FILE, timestamp)),
+            n => dir.join(format!("{}.{}-{}.bak", CONFIG_FILE, timestamp, n)),
         })
-        .collect::<Vec<String>>();
+        .find(|path| !path.exists())
+        .expect("an unused backup name")
+}
+
+// Convert the flat `key = "value"` file used before versioning into the typed model.
+fn migrate_flat_config(table: &toml::Table) -> Config {
+    let mut config = Config { version: CONFIG_VERSION, ..Default::default() };
+    let mut legacy_account = AccountConfig::default();
 
-    if !updated {
-        let new_line = format!("{} = \"{}\"", key, value);
-        config_content.push('\n');
-        config_content.push_str(&new_line);
+    for (key, value) in table {
+        let value = match value {
+            toml::Value::String(value) => value.clone(),
+            other => other.to_string(),
+        };
+        match key.as_str() {
+            "rpc_url" => config.defaults.rpc_url = Some(value),
+            "pinata_jwt" => config.ipfs.pinata_jwt = Some(value),
+            "mnemonic" => legacy_account.mnemonic = Some(value),
+            "secret_uri" => legacy_account.secret_uri = Some(value),
+            other => eprintln!("{}", format!("⚠️ Dropping unknown config key '{}'.", other).yellow()),
+        }
+    }
+
+    if legacy_account.mnemonic.is_some() || legacy_account.secret_uri.is_some() {
+        config.accounts.insert(DEFAULT_ACCOUNT.to_string(), legacy_account);
     }
+    config
+}
 
-    write_config_file(&new_content.join("\n"))?;
+pub async fn set_account(name: Option<String>, mnemonic: Option<String>, secret_uri: Option<String>) -> Result<()> {
+    let name = name.unwrap_or_else(|| DEFAULT_ACCOUNT.to_string());
+    let address = create_account(&name, mnemonic, secret_uri, None).await?;
+    make_default_account(&name)?;
+    output::emit(json!({ "account": name, "address": address, "selected": true }));
     Ok(())
 }
 
-pub async fn set_account(mnemonic: Option<String>, secret_uri: Option<String>) -> Result<()> {
-    match (mnemonic, secret_uri) {
-        (Some(mnemonic), None) => set_account_from_mnemonic(mnemonic).await,
-        (None, Some(secret_uri)) => set_account_from_uri(secret_uri).await,
-        _ => Err("No mnemonic or secret URI provided.".into()),
+/// Add a named account from a mnemonic, secret URI or polkadot-js JSON keystore.
+pub async fn add_account(
+    name: String,
+    mnemonic: Option<String>,
+    secret_uri: Option<String>,
+    keystore_path: Option<String>,
+) -> Result<()> {
+    let address = create_account(&name, mnemonic, secret_uri, keystore_path).await?;
+    output::emit(json!({ "account": name, "address": address }));
+    Ok(())
+}
+
+// Store a new named account and return its address.
+async fn create_account(
+    name: &str,
+    mnemonic: Option<String>,
+    secret_uri: Option<String>,
+    keystore_path: Option<String>,
+) -> Result<String> {
+    validate_account_name(name)?;
+    match (mnemonic, secret_uri, keystore_path) {
+        (Some(mnemonic), None, None) => set_account_from_mnemonic(name, mnemonic).await,
+        (None, Some(secret_uri), None) => set_account_from_uri(name, secret_uri).await,
+        (None, None, Some(path)) => import_keystore_file(name, &path),
+        _ => Err(Error::invalid_input("Provide exactly one of a mnemonic, secret URI or keystore file.")),
     }
 }
 
-async fn set_account_from_mnemonic(mnemonic: String) -> Result<()> {
+async fn set_account_from_mnemonic(name: &str, mnemonic: String) -> Result<String> {
     let mnemonic = Mnemonic::parse(&mnemonic)
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-    Keypair::from_phrase(&mnemonic, None)?;
-    update_config("mnemonic", &mnemonic.to_string())?;
-    println!("Account mnemonic saved successfully.");
-    Ok(())
+        .map_err(Error::invalid_input)?;
+    let keypair = Keypair::from_phrase(&mnemonic, None)?;
+    let address = save_keypair_to_keystore(name, &keypair)?;
+    say!("Account '{}' saved to encrypted keystore successfully.", name);
+    Ok(address)
 }
 
-async fn set_account_from_uri(secret_uri: String) -> Result<()> {
+async fn set_account_from_uri(name: &str, secret_uri: String) -> Result<String> {
     let suri = SecretUri::from_str(&secret_uri)
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
-    Keypair::from_uri(&suri)?;
-    update_config("secret_uri", &secret_uri)?;
-    println!("Account secret URI saved successfully.");
+        .map_err(Error::invalid_input)?;
+    let keypair = Keypair::from_uri(&suri)?;
+    let address = save_keypair_to_keystore(name, &keypair)?;
+    say!("Account '{}' saved to encrypted keystore successfully.", name);
+    Ok(address)
+}
+
+fn save_keypair_to_keystore(name: &str, keypair: &Keypair) -> Result<String> {
+    let password = keystore::read_new_password()?;
+    let keystore = KeystoreJson::encrypt(keypair, &password, name)?;
+    register_keystore(name, &keystore)?;
+    Ok(keystore.address)
+}
+
+// Store the keystore for a named account, replacing any plaintext secrets it had.
+fn register_keystore(name: &str, keystore: &KeystoreJson) -> Result<()> {
+    let path = account_keystore_path(name);
+    keystore.save(&path)?;
+
+    let mut config = load_config()?;
+    config.accounts.insert(
+        name.to_string(),
+        AccountConfig {
+            address: Some(keystore.address.clone()),
+            keystore: Some(path.to_string_lossy().into_owned()),
+            ..Default::default()
+        },
+    );
+    save_config(&config)
+}
+
+fn validate_account_name(name: &str) -> Result<()> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(Error::invalid_input("Account names may only contain letters, digits, '-' and '_'."));
+    }
+    Ok(())
+}
+
+/// Import a polkadot-js JSON keystore as a named account.
+pub async fn import_keystore(name: Option<String>, path: String) -> Result<()> {
+    let name = name.unwrap_or_else(|| DEFAULT_ACCOUNT.to_string());
+    validate_account_name(&name)?;
+    let address = import_keystore_file(&name, &path)?;
+    output::emit(json!({ "account": name, "address": address }));
+    Ok(())
+}
+
+fn import_keystore_file(name: &str, path: &str) -> Result<String> {
+    let keystore = KeystoreJson::load(Path::new(path))?;
+    let password = keystore::read_password("🔑 Keystore password: ")?;
+    let keypair = keystore.decrypt(&password)?;
+
+    register_keystore(name, &keystore)?;
+
+    let account: AccountId32 = keypair.public_key().into();
+    say!("Keystore for {} imported as account '{}'.", account, name);
+    Ok(account.to_string())
+}
+
+/// Export the selected account as a polkadot-js JSON keystore.
+pub async fn export_keystore(path: String) -> Result<()> {
+    let config = load_config()?;
+    let name = selected_account_name(&config);
+    let account = find_account(&config, &name)?;
+
+    let keystore = match &account.keystore {
+        Some(keystore_path) => KeystoreJson::load(Path::new(keystore_path))?,
+        None => {
+            // Accounts configured before the keystore existed still hold a plaintext secret.
+            let keypair = keypair_from_legacy_secret(account)?;
+            let password = keystore::read_new_password()?;
+            KeystoreJson::encrypt(&keypair, &password, &name)?
+        }
+    };
+
+    keystore.save(Path::new(&path))?;
+    say!("Keystore for {} exported to {}.", keystore.address, path);
+    output::emit(json!({ "account": name, "address": keystore.address, "path": path }));
+    Ok(())
+}
+
+/// Make a named account the default signer.
+pub async fn use_account(name: String) -> Result<()> {
+    let address = make_default_account(&name)?;
+    output::emit(json!({ "account": name, "address": address, "selected": true }));
     Ok(())
 }
 
+fn make_default_account(name: &str) -> Result<Option<String>> {
+    let mut config = load_config()?;
+    let address = find_account(&config, name)?.address.clone();
+    config.defaults.account = Some(name.to_string());
+    save_config(&config)?;
+    say!("Now using account '{}'.", name);
+    Ok(address)
+}
+
+/// List the named accounts, marking the one currently selected.
+pub async fn list_accounts() -> Result<()> {
+    let config = load_config()?;
+    let selected = selected_account_name(&config);
+
+    output::emit(json!({
+        "accounts": config
+            .accounts
+            .iter()
+            .map(|(name, account)| json!({ "name": name, "address": account.address, "selected": *name == selected }))
+            .collect::<Vec<_>>(),
+    }));
+
+    if config.accounts.is_empty() {
+        say!("{}", "❌ No accounts configured. Use `account add <name>` to add one.".red().bold());
+        return Ok(());
+    }
+
+    say!("{}", "📜 Accounts".blue().bold());
+    for (name, account) in &config.accounts {
+        let address = account
+            .address
+            .clone()
+            .unwrap_or_else(|| "<plaintext secret, run set-account to encrypt>".to_string());
+        let marker = if *name == selected { "*" } else { " " };
+        say!(
+            "{} {}: {}",
+            marker.green().bold(),
+            name.cyan().bold(),
+            address.bright_white()
+        );
+    }
+    Ok(())
+}
+
+fn accounts_dir_path() -> PathBuf {
+    config_dir_path().join(ACCOUNTS_DIR)
+}
+
+fn account_keystore_path(name: &str) -> PathBuf {
+    accounts_dir_path().join(format!("{}.json", name))
+}
+
+/// Select the account used for signing, overriding the configured default (`--from`).
+pub fn select_account(name: String) {
+    ACCOUNT_OVERRIDE.set(name).ok();
+}
+
+fn selected_account_name(config: &Config) -> String {
+    ACCOUNT_OVERRIDE
+        .get()
+        .cloned()
+        .or_else(|| config.defaults.account.clone())
+        .unwrap_or_else(|| DEFAULT_ACCOUNT.to_string())
+}
+
+fn find_account<'a>(config: &'a Config, name: &str) -> Result<&'a AccountConfig> {
+    if config.accounts.is_empty() {
+        return Err(Error::config("No account configured. Use `set-account` or `account add` first."));
+    }
+    config
+        .accounts
+        .get(name)
+        .ok_or_else(|| Error::config(format!("Account '{}' not found.", name)))
+}
+
 pub async fn set_rpc_url(url: String) -> Result<()> {
     if OnlineClient::<PolkadotConfig>::from_url(&url).await.is_err() {
-        return Err(io::Error::new(io::ErrorKind::InvalidInput, "Failed to connect to the provided RPC URL.").into());
+        return Err(Error::Rpc("Failed to connect to the provided RPC URL.".to_string()));
+    }
+
+    let mut config = load_config()?;
+    config.defaults.rpc_url = Some(url.clone());
+    save_config(&config)?;
+    say!("RPC URL saved successfully.");
+    output::emit(json!({ "rpc_url": url }));
+    Ok(())
+}
+
+pub async fn set_network(name: String) -> Result<()> {
+    let profile = find_network(&name)?;
+
+    let mut config = load_config()?;
+    config.defaults.network = Some(profile.name.clone());
+    // A custom RPC URL would otherwise keep pointing at the previous chain.
+    config.defaults.rpc_url = None;
+    save_config(&config)?;
+    say!("Network set to {} ({}).", profile.name, profile.rpc_url);
+    output::emit(json!({ "network": profile.name, "rpc_url": profile.rpc_url }));
+    Ok(())
+}
+
+/// Print a single config value addressed by a dotted key, e.g. `defaults.network`.
+pub async fn config_get(key: String) -> Result<()> {
+    let value = toml::Value::try_from(load_config()?)?;
+    match lookup_key(&value, &key) {
+        Some(toml::Value::String(s)) => say!("{}", s),
+        Some(other) => say!("{}", other),
+        None => return Err(Error::invalid_input(format!("Config key '{}' is not set.", key))),
     }
+    output::emit(json!({ "key": key, "value": lookup_key(&value, &key) }));
+    Ok(())
+}
+
+/// Set a config value addressed by a dotted key. Values are parsed as TOML, falling back to a string.
+pub async fn config_set(key: String, raw_value: String) -> Result<()> {
+    let value = format!("value = {}", raw_value)
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|mut table| table.remove("value"))
+        .unwrap_or(toml::Value::String(raw_value));
+
+    update_config_value(&key, Some(value.clone()))?;
+    say!("{} updated.", key);
+    output::emit(json!({ "key": key, "value": value }));
+    Ok(())
+}
 
-    update_config("rpc_url", &url)?;
-    println!("RPC URL saved successfully.");
+/// Remove a config value addressed by a dotted key.
+pub async fn config_unset(key: String) -> Result<()> {
+    update_config_value(&key, None)?;
+    say!("{} removed.", key);
+    output::emit(json!({ "key": key, "value": null }));
     Ok(())
 }
 
-fn load_value_from_config(key: &str) -> Result<Option<String>> {
-    let config_content = read_config_file()?;
-    Ok(config_content
-        .lines()
-        .find_map(|line| {
-            if line.starts_with(key) {
-                line.splitn(2, " = ").nth(1).map(|value| value.trim_matches('"').to_string())
+/// Print the whole config file, masking secrets.
+pub async fn config_show() -> Result<()> {
+    let mut value = toml::Value::try_from(load_config()?)?;
+    mask_secrets(&mut value);
+    say!("{}", "⚙️ Configuration".blue().bold());
+    say!("{}", toml::to_string_pretty(&value)?);
+    output::emit(serde_json::to_value(&value)?);
+    Ok(())
+}
+
+fn lookup_key<'a>(value: &'a toml::Value, key: &str) -> Option<&'a toml::Value> {
+    key.split('.').try_fold(value, |value, part| value.get(part))
+}
+
+// Apply the change to the serialized config and re-parse it so type errors are rejected.
+fn update_config_value(key: &str, new_value: Option<toml::Value>) -> Result<()> {
+    let parts: Vec<&str> = key.split('.').collect();
+    if parts.iter().any(|part| part.is_empty()) || parts[0] == "version" {
+        return Err(Error::invalid_input(format!("Invalid config key '{}'.", key)));
+    }
+
+    let mut value = toml::Value::try_from(load_config()?)?;
+    let (leaf, path) = parts.split_last().expect("split always yields a part");
+    let mut table = value.as_table_mut().expect("config serializes to a table");
+    for part in path {
+        table = table
+            .entry(part.to_string())
+            .or_insert_with(|| toml::Value::Table(Default::default()))
+            .as_table_mut()
+            .ok_or_else(|| Error::invalid_input(format!("Config key '{}' is not a section.", part)))?;
+    }
+
+    match new_value {
+        Some(new_value) => {
+            table.insert(leaf.to_string(), new_value);
+        }
+        None => {
+            table
+                .remove(*leaf)
+                .ok_or_else(|| Error::invalid_input(format!("Config key '{}' is not set.", key)))?;
+        }
+    }
+
+    let config: Config = value
+        .try_into()
+        .map_err(|e| Error::invalid_input(format!("Invalid value for '{}': {}", key, e)))?;
+    save_config(&config)
+}
+
+fn mask_secrets(value: &mut toml::Value) {
+    if let Some(table) = value.as_table_mut() {
+        for (key, value) in table.iter_mut() {
+            if SECRET_KEYS.contains(&key.as_str()) {
+                *value = toml::Value::String("********".to_string());
             } else {
-                None
+                mask_secrets(value);
             }
-        }))
+        }
+    }
 }
 
 pub fn load_account_from_config() -> Result<Keypair> {
-    if let Some(mnemonic) = load_value_from_config("mnemonic")? {
-        let mnemonic = Mnemonic::parse(&mnemonic)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let config = load_config()?;
+    let name = selected_account_name(&config);
+    let account = find_account(&config, &name)?;
+
+    if let Some(keystore_path) = &account.keystore {
+        let keystore = KeystoreJson::load(Path::new(keystore_path))?;
+        let password = keystore::read_password(&format!("🔑 Password for '{}' ({}): ", name, keystore.address))?;
+        return keystore.decrypt(&password);
+    }
+
+    let keypair = keypair_from_legacy_secret(account)?;
+    eprintln!(
+        "{}",
+        "⚠️ Your account secret is stored in plaintext. Run `polkacli set-account` again to encrypt it."
+            .yellow()
+            .bold()
+    );
+    Ok(keypair)
+}
+
+/// Load the account that signs the extrinsics of a command.
+///
+/// With `tx build` only the address is needed, so the keystore is not unlocked.
+pub fn load_signer_from_config() -> Result<AccountSigner> {
+    if tx_utils::is_build() {
+        return load_address_from_config().map(AccountSigner::Address);
+    }
+    load_account_from_config().map(|keypair| AccountSigner::Keypair(Box::new(keypair)))
+}
+
+/// The address of the selected account, read without unlocking its keystore when the config records it.
+pub fn load_address_from_config() -> Result<AccountId32> {
+    let config = load_config()?;
+    let name = selected_account_name(&config);
+    match &find_account(&config, &name)?.address {
+        Some(address) => parse_address(address),
+        None => Ok(load_account_from_config()?.public_key().into()),
+    }
+}
+
+fn keypair_from_legacy_secret(account: &AccountConfig) -> Result<Keypair> {
+    if let Some(mnemonic) = &account.mnemonic {
+        let mnemonic = Mnemonic::parse(mnemonic)
+            .map_err(Error::invalid_input)?;
         Keypair::from_phrase(&mnemonic, None)
-            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
-    } else if let Some(secret_uri) = load_value_from_config("secret_uri")? {
-        let suri = SecretUri::from_str(&secret_uri)
-            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    } else if let Some(secret_uri) = &account.secret_uri {
+        let suri = SecretUri::from_str(secret_uri)
+            .map_err(Error::invalid_input)?;
         Keypair::from_uri(&suri)
-            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
     } else {
-        Err(io::Error::new(io::ErrorKind::InvalidInput, "No valid mnemonic or secret URI found in config file.").into())
+        Err(Error::config("Account has no keystore or secret configured."))
     }
 }
 
 pub fn load_rpc_url_from_config() -> Result<String> {
-    load_value_from_config("rpc_url")?.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "No valid RPC URL found in config file.").into())
+    load_config()?
+        .defaults
+        .rpc_url
+        .ok_or_else(|| Error::config("No valid RPC URL found in config file."))
+}
+
+pub fn load_network_from_config() -> Result<Option<String>> {
+    Ok(load_config()?.defaults.network)
 }
 
 pub fn load_pinata_jwt_from_config() -> Result<Option<String>> {
-    load_value_from_config("pinata_jwt")
+    Ok(load_config()?.ipfs.pinata_jwt)
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("polkacli-config-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn backups(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .filter(|name| name.ends_with(".bak"))
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn migrates_legacy_flat_config() {
+        let dir = test_dir("legacy");
+        let legacy = "rpc_url = \"ws://127.0.0.1:9944\"\npinata_jwt = \"jwt\"\nsecret_uri = \"//Alice\"\n";
+        fs::write(dir.join(CONFIG_FILE), legacy).unwrap();
+
+        let config = load_config_in(&dir).unwrap();
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.defaults.rpc_url.as_deref(), Some("ws://127.0.0.1:9944"));
+        assert_eq!(config.ipfs.pinata_jwt.as_deref(), Some("jwt"));
+        assert_eq!(config.accounts[DEFAULT_ACCOUNT].secret_uri.as_deref(), Some("//Alice"));
+
+        let first_backup = match backups(&dir).as_slice() {
+            [backup] => backup.clone(),
+            other => panic!("expected one backup, found {:?}", other),
+        };
+        assert_eq!(fs::read_to_string(dir.join(&first_backup)).unwrap(), legacy);
+        let saved = load_config_in(&dir).unwrap();
+        assert_eq!(saved.accounts[DEFAULT_ACCOUNT].secret_uri.as_deref(), Some("//Alice"));
+
+        // A second migration keeps the first backup.
+        fs::write(dir.join(CONFIG_FILE), "rpc_url = \"ws://127.0.0.1:9944\"\n").unwrap();
+        load_config_in(&dir).unwrap();
+        assert_eq!(backups(&dir).len(), 2);
+        assert!(backups(&dir).contains(&first_backup));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn rejects_malformed_config_without_migrating() {
+        let dir = test_dir("malformed");
+        let malformed = "version = 1\n\n[accounts.default]\naddress = \"5Grw\"\nkeystore = \n";
+        let without_version = "[accounts.default]\naddress = \"5Grw\"\n";
+        for content in [malformed, without_version] {
+            fs::write(dir.join(CONFIG_FILE), content).unwrap();
+            assert!(matches!(load_config_in(&dir), Err(Error::Config(_))));
+            assert_eq!(fs::read_to_string(dir.join(CONFIG_FILE)).unwrap(), content);
+            assert!(backups(&dir).is_empty());
+        }
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,28 +1,231 @@
-use std::error::Error;
+use serde_json::{json, Value};
 use std::fmt;
+use std::io;
+use subxt::error::DispatchError;
 
-pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
+pub type Result<T> = std::result::Result<T, Error>;
 
+/// Everything that can make a command fail.
+///
+/// Each variant maps to a stable process exit code (see [`Error::exit_code`]),
+/// so scripts can tell configuration problems apart from on-chain failures.
 #[derive(Debug)]
-pub struct CustomError(String);
+pub enum Error {
+    /// Invalid arguments or input files (exit code 2).
+    InvalidInput(String),
+    /// Missing or broken configuration, accounts or keystores (exit code 3).
+    Config(String),
+    /// The RPC endpoint could not be reached or returned an error (exit code 4).
+    Rpc(String),
+    /// The extrinsic was included but failed to dispatch (exit code 5).
+    Dispatch {
+        /// Pallet that raised the error, for module errors.
+        pallet: Option<String>,
+        /// Error variant, e.g. `NoPermission`.
+        error: String,
+        /// Documentation of the error variant from the runtime metadata.
+        docs: String,
+        /// Fee in plancks that was charged even though the extrinsic failed.
+        fee: Option<u128>,
+    },
+    /// Pinning to or fetching from IPFS failed (exit code 6).
+    Ipfs(String),
+    /// Any other failure (exit code 1).
+    Other(String),
+}
+
+impl Error {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Error::Other(_) => 1,
+            Error::InvalidInput(_) => 2,
+            Error::Config(_) => 3,
+            Error::Rpc(_) => 4,
+            Error::Dispatch { .. } => 5,
+            Error::Ipfs(_) => 6,
+        }
+    }
+
+    /// Short machine-readable name of the error category.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Error::Other(_) => "other",
+            Error::InvalidInput(_) => "invalid_input",
+            Error::Config(_) => "config",
+            Error::Rpc(_) => "rpc",
+            Error::Dispatch { .. } => "dispatch",
+            Error::Ipfs(_) => "ipfs",
+        }
+    }
+
+    /// The error as reported by `--output json`.
+    pub fn to_json(&self) -> Value {
+        let mut error = json!({
+            "kind": self.kind(),
+            "exit_code": self.exit_code(),
+            "message": self.to_string(),
+        });
+        if let Error::Dispatch { pallet, error: variant, docs, fee } = self {
+            error["pallet"] = json!(pallet);
+            error["error"] = json!(variant);
+            error["docs"] = json!(docs);
+            error["fee"] = json!(fee.map(|fee| fee.to_string()));
+        }
+        error
+    }
+
+    pub fn invalid_input(message: impl fmt::Display) -> Self {
+        Error::InvalidInput(message.to_string())
+    }
+
+    pub fn config(message: impl fmt::Display) -> Self {
+        Error::Config(message.to_string())
+    }
 
-impl fmt::Display for CustomError {
+    pub fn ipfs(message: impl fmt::Display) -> Self {
+        Error::Ipfs(message.to_string())
+    }
+}
+
+impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+        match self {
+            Error::InvalidInput(message)
+            | Error::Config(message)
+            | Error::Rpc(message)
+            | Error::Ipfs(message)
+            | Error::Other(message) => write!(f, "{}", message),
+            Error::Dispatch { pallet, error, docs, .. } => {
+                match pallet {
+                    Some(pallet) => write!(f, "{}::{}", pallet, error)?,
+                    None => write!(f, "{}", error)?,
+                }
+                if !docs.is_empty() {
+                    write!(f, " — {}", docs)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<DispatchError> for Error {
+    fn from(e: DispatchError) -> Self {
+        match &e {
+            DispatchError::Module(module) => match module.details() {
+                Ok(details) => Error::Dispatch {
+                    pallet: Some(details.pallet.name().to_string()),
+                    error: details.variant.name.clone(),
+                    docs: details.variant.docs.join(" ").trim().to_string(),
+                    fee: None,
+                },
+                Err(_) => Error::Dispatch {
+                    pallet: None,
+                    error: format!("Module({:?})", module.bytes()),
+                    docs: String::new(),
+                    fee: None,
+                },
+            },
+            other => Error::Dispatch {
+                pallet: None,
+                error: format!("{:?}", other),
+                docs: other.to_string(),
+                fee: None,
+            },
+        }
+    }
+}
+
+impl From<subxt::Error> for Error {
+    fn from(e: subxt::Error) -> Self {
+        match e {
+            subxt::Error::Runtime(dispatch_error) => dispatch_error.into(),
+            // Only failures to reach the node are RPC errors; the rest are local bugs or bad data.
+            subxt::Error::Rpc(_) | subxt::Error::Io(_) => Error::Rpc(e.to_string()),
+            other => Error::Other(other.to_string()),
+        }
+    }
+}
+
+impl From<subxt::ext::subxt_core::Error> for Error {
+    fn from(e: subxt::ext::subxt_core::Error) -> Self {
+        subxt::Error::from(e).into()
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        match e.kind() {
+            io::ErrorKind::InvalidInput | io::ErrorKind::NotFound => Error::InvalidInput(e.to_string()),
+            _ => Error::Other(e.to_string()),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Error::Ipfs(e.to_string())
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(e: serde_json::Error) -> Self {
+        Error::InvalidInput(e.to_string())
     }
 }
 
-impl Error for CustomError {}
+impl From<toml::ser::Error> for Error {
+    fn from(e: toml::ser::Error) -> Self {
+        Error::Config(e.to_string())
+    }
+}
 
-impl From<&str> for CustomError {
-    fn from(s: &str) -> Self {
-        CustomError(s.to_string())
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::Other(message)
     }
 }
 
-impl From<String> for CustomError {
-    fn from(s: String) -> Self {
-        CustomError(s)
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::Other(message.to_string())
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use subxt::error::{MetadataError, RpcError};
+
+    #[test]
+    fn maps_rpc_errors() {
+        let error: Error = subxt::Error::Rpc(RpcError::SubscriptionDropped).into();
+        assert!(matches!(error, Error::Rpc(_)));
+        let error: Error = subxt::Error::Rpc(RpcError::request_rejected("busy")).into();
+        assert!(matches!(error, Error::Rpc(_)));
+    }
+
+    #[test]
+    fn maps_io_errors() {
+        let error: Error = subxt::Error::Io(io::Error::from(io::ErrorKind::ConnectionReset)).into();
+        assert!(matches!(error, Error::Rpc(_)));
+    }
+
+    #[test]
+    fn maps_runtime_errors() {
+        let error: Error = subxt::Error::Runtime(DispatchError::BadOrigin).into();
+        assert!(matches!(error, Error::Dispatch { pallet: None, .. }));
+    }
+
+    #[test]
+    fn maps_other_errors() {
+        let error: Error = subxt::Error::Codec("bad input".into()).into();
+        assert!(matches!(error, Error::Other(_)));
+        let error: Error = subxt::Error::Metadata(MetadataError::PalletNameNotFound("Nfts".to_string())).into();
+        assert!(matches!(error, Error::Other(_)));
+        let error: Error = subxt::Error::Unknown(vec![0]).into();
+        assert!(matches!(error, Error::Other(_)));
+    }
+}
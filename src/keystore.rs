@@ -0,0 +1,258 @@
+use crate::error::{Error, Result};
+use crate::signer::Keypair;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use crypto_secretbox::{
+    aead::{Aead, KeyInit},
+    Nonce, XSalsa20Poly1305,
+};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::{env, fs, io, path::Path};
+use subxt::utils::AccountId32;
+
+/// Environment variable consulted before prompting for a keystore password.
+pub const PASSWORD_ENV: &str = "POLKACLI_PASSWORD";
+
+// Layout of the polkadot-js PKCS#8 envelope around an sr25519 secret key.
+const PKCS8_HEADER: [u8; 16] = [48, 83, 2, 1, 1, 48, 5, 6, 3, 43, 101, 112, 4, 34, 4, 32];
+const PKCS8_DIVIDER: [u8; 5] = [161, 35, 3, 33, 0];
+const SECRET_KEY_LENGTH: usize = 64;
+const PUBLIC_KEY_LENGTH: usize = 32;
+
+// scrypt parameters used by polkadot-js when exporting accounts.
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+const SALT_LENGTH: usize = 32;
+const SCRYPT_HEADER_LENGTH: usize = SALT_LENGTH + 12;
+const NONCE_LENGTH: usize = 24;
+
+/// A password-encrypted account in the polkadot-js JSON keystore format.
+#[derive(Serialize, Deserialize)]
+pub struct KeystoreJson {
+    pub encoded: String,
+    pub encoding: Encoding,
+    pub address: String,
+    #[serde(default)]
+    pub meta: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Encoding {
+    pub content: Vec<String>,
+    #[serde(rename = "type")]
+    pub kind: Vec<String>,
+    pub version: String,
+}
+
+impl KeystoreJson {
+    /// Encrypt a keypair with the given password.
+    pub fn encrypt(keypair: &Keypair, password: &str, name: &str) -> Result<Self> {
+        let public = keypair.public_key();
+
+        let mut plaintext = Vec::with_capacity(
+            PKCS8_HEADER.len() + SECRET_KEY_LENGTH + PKCS8_DIVIDER.len() + PUBLIC_KEY_LENGTH,
+        );
+        plaintext.extend_from_slice(&PKCS8_HEADER);
+        plaintext.extend_from_slice(&keypair.to_ed25519_bytes());
+        plaintext.extend_from_slice(&PKCS8_DIVIDER);
+        plaintext.extend_from_slice(&public.0);
+
+        let mut salt = [0u8; SALT_LENGTH];
+        let mut nonce = [0u8; NONCE_LENGTH];
+        rand::thread_rng().fill_bytes(&mut salt);
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let key = derive_key(password, &salt, SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P)?;
+        let ciphertext = XSalsa20Poly1305::new(&key.into())
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+            .map_err(|_| Error::config("Failed to encrypt keystore."))?;
+
+        let mut encoded = Vec::with_capacity(SCRYPT_HEADER_LENGTH + NONCE_LENGTH + ciphertext.len());
+        encoded.extend_from_slice(&salt);
+        encoded.extend_from_slice(&(1u32 << SCRYPT_LOG_N).to_le_bytes());
+        encoded.extend_from_slice(&SCRYPT_P.to_le_bytes());
+        encoded.extend_from_slice(&SCRYPT_R.to_le_bytes());
+        encoded.extend_from_slice(&nonce);
+        encoded.extend_from_slice(&ciphertext);
+
+        let when_created = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or_default();
+
+        Ok(KeystoreJson {
+            encoded: BASE64.encode(encoded),
+            encoding: Encoding {
+                content: vec!["pkcs8".into(), "sr25519".into()],
+                kind: vec!["scrypt".into(), "xsalsa20-poly1305".into()],
+                version: "3".into(),
+            },
+            address: AccountId32::from(public).to_string(),
+            meta: serde_json::json!({ "name": name, "whenCreated": when_created }),
+        })
+    }
+
+    /// Decrypt the keystore with the given password.
+    pub fn decrypt(&self, password: &str) -> Result<Keypair> {
+        if self.encoding.version != "3" || !self.encoding.kind.iter().any(|t| t == "scrypt") {
+            return Err(Error::config("Unsupported keystore encoding; only scrypt/xsalsa20-poly1305 (version 3) is supported."));
+        }
+        if !self.encoding.content.iter().any(|c| c == "sr25519") {
+            return Err(Error::config("Unsupported keystore key type; only sr25519 accounts are supported."));
+        }
+
+        let encoded = BASE64
+            .decode(&self.encoded)
+            .map_err(|e| Error::config(format!("Invalid keystore encoding: {}", e)))?;
+        if encoded.len() < SCRYPT_HEADER_LENGTH + NONCE_LENGTH {
+            return Err(Error::config("Keystore data is truncated."));
+        }
+
+        let (salt, rest) = encoded.split_at(SALT_LENGTH);
+        let n = read_u32_le(&rest[0..4]);
+        let p = read_u32_le(&rest[4..8]);
+        let r = read_u32_le(&rest[8..12]);
+        // The parameters come from the file, so cap them before deriving: a crafted keystore
+        // could otherwise make scrypt allocate gigabytes.
+        if !n.is_power_of_two() || n > 1 << SCRYPT_LOG_N || r != SCRYPT_R || p != SCRYPT_P {
+            return Err(Error::config(format!(
+                "Unsupported scrypt parameters in keystore (N = {}, r = {}, p = {}); polkadot-js keystores use N ≤ {}, r = {} and p = {}.",
+                n, r, p, 1u32 << SCRYPT_LOG_N, SCRYPT_R, SCRYPT_P
+            )));
+        }
+
+        let key = derive_key(password, salt, n.trailing_zeros() as u8, r, p)?;
+        let nonce = &rest[12..12 + NONCE_LENGTH];
+        let plaintext = XSalsa20Poly1305::new(&key.into())
+            .decrypt(Nonce::from_slice(nonce), &rest[12 + NONCE_LENGTH..])
+            .map_err(|_| Error::config("Unable to decrypt keystore; the password is incorrect."))?;
+
+        let secret_start = PKCS8_HEADER.len();
+        let divider_start = secret_start + SECRET_KEY_LENGTH;
+        let public_start = divider_start + PKCS8_DIVIDER.len();
+        if plaintext.len() < public_start + PUBLIC_KEY_LENGTH
+            || plaintext[..secret_start] != PKCS8_HEADER
+            || plaintext[divider_start..public_start] != PKCS8_DIVIDER
+        {
+            return Err(Error::config("Invalid PKCS#8 contents in keystore."));
+        }
+
+        let keypair = Keypair::from_ed25519_bytes(&plaintext[secret_start..divider_start])?;
+        if keypair.public_key().0[..] != plaintext[public_start..public_start + PUBLIC_KEY_LENGTH] {
+            return Err(Error::config("Keystore public key does not match its secret key."));
+        }
+        Ok(keypair)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            Error::config(format!("Failed to read keystore at {}: {}", path.display(), e))
+        })?;
+        serde_json::from_str(&content).map_err(|e| {
+            Error::config(format!("Failed to parse keystore at {}: {}", path.display(), e))
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        restrict_permissions(path)?;
+        Ok(())
+    }
+}
+
+fn read_u32_le(bytes: &[u8]) -> u32 {
+    u32::from_le_bytes(bytes.try_into().expect("slice is four bytes long"))
+}
+
+fn derive_key(password: &str, salt: &[u8], log_n: u8, r: u32, p: u32) -> Result<[u8; 32]> {
+    let params = scrypt::Params::new(log_n, r, p, 64)
+        .map_err(|e| Error::config(format!("Invalid scrypt parameters: {}", e)))?;
+    let mut output = [0u8; 64];
+    scrypt::scrypt(password.as_bytes(), salt, &params, &mut output)
+        .map_err(|e| Error::config(format!("Key derivation failed: {}", e)))?;
+
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&output[..32]);
+    Ok(key)
+}
+
+#[cfg(unix)]
+fn restrict_permissions(path: &Path) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    fs::set_permissions(path, fs::Permissions::from_mode(0o600))
+}
+
+#[cfg(not(unix))]
+fn restrict_permissions(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+/// Read the keystore password from `POLKACLI_PASSWORD`, or prompt for it.
+pub fn read_password(prompt: &str) -> Result<String> {
+    if let Ok(password) = env::var(PASSWORD_ENV) {
+        return Ok(password);
+    }
+    Ok(rpassword::prompt_password(prompt)?)
+}
+
+/// Read a new keystore password, asking for confirmation when prompting interactively.
+pub fn read_new_password() -> Result<String> {
+    if let Ok(password) = env::var(PASSWORD_ENV) {
+        return Ok(password);
+    }
+    let password = rpassword::prompt_password("🔑 New keystore password: ")?;
+    let confirmation = rpassword::prompt_password("🔑 Confirm password: ")?;
+    if password != confirmation {
+        return Err(Error::invalid_input("Passwords do not match."));
+    }
+    Ok(password)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use subxt_signer::sr25519::dev;
+
+    // Alice's account in the polkadot-js keystore format (password `polkacli-test`), encoded
+    // independently of this module with scrypt and an XSalsa20-Poly1305 secretbox.
+    const ALICE_KEYSTORE: &str = r#"{"encoded": "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8AgAAAAQAAAAgAAABkZWZnaGlqa2xtbm9wcXJzdHV2d3h5enuDOUEbvYlnfAxVmZay7A9KZD1IK4CkUfUXCnf46S9LoBeqho+IZftm4iH6aqUkPWV63OoAjYOlSFWMQvC3xfy2uAPKOt23WFMVIg42xd0Mae2PlFCznB2T+WeGcGrdeqMV/xxClRfY2CFuZJuAle4P4AbUKunOICa92h4bbGz/A2GDnKcO", "encoding": {"content": ["pkcs8", "sr25519"], "type": ["scrypt", "xsalsa20-poly1305"], "version": "3"}, "address": "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY", "meta": {"name": "alice", "whenCreated": 1700000000000}}"#;
+
+    fn alice() -> Keypair {
+        Keypair::from_uri(&"//Alice".parse().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn encrypt_then_decrypt_round_trips() {
+        let keystore = KeystoreJson::encrypt(&alice(), "correct horse", "alice").unwrap();
+        assert_eq!(keystore.address, AccountId32::from(dev::alice().public_key()).to_string());
+
+        let keypair = keystore.decrypt("correct horse").unwrap();
+        assert_eq!(keypair.to_ed25519_bytes(), alice().to_ed25519_bytes());
+        assert!(keystore.decrypt("wrong horse").is_err());
+    }
+
+    #[test]
+    fn decrypts_polkadot_js_keystore() {
+        let keystore: KeystoreJson = serde_json::from_str(ALICE_KEYSTORE).unwrap();
+        let keypair = keystore.decrypt("polkacli-test").unwrap();
+        assert_eq!(keypair.public_key().0, dev::alice().public_key().0);
+    }
+
+    #[test]
+    fn rejects_oversized_scrypt_parameters() {
+        let keystore: KeystoreJson = serde_json::from_str(ALICE_KEYSTORE).unwrap();
+        let original = BASE64.decode(&keystore.encoded).unwrap();
+        // N, p and r follow the 32 byte salt.
+        for (offset, value) in [(0, 1u32 << 20), (4, 2), (8, 16)] {
+            let mut encoded = original.clone();
+            encoded[SALT_LENGTH + offset..SALT_LENGTH + offset + 4].copy_from_slice(&value.to_le_bytes());
+            let tampered = KeystoreJson { encoded: BASE64.encode(encoded), ..serde_json::from_str(ALICE_KEYSTORE).unwrap() };
+            let error = tampered.decrypt("polkacli-test").err().unwrap().to_string();
+            assert!(error.contains("Unsupported scrypt parameters"), "{}", error);
+        }
+    }
+}
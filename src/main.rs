@@ -1,18 +1,53 @@
+#[macro_use]
+mod output;
 mod cli;
 mod commands;
 mod config;
 mod error;
 mod client;
+mod keystore;
+mod network;
+mod signer;
 mod utils;
 
 use clap::Parser;
 use cli::Cli;
 use commands::run_command;
+use colored::*;
 use error::Result;
+use serde_json::json;
+use utils::tx_utils;
 
 #[tokio::main]
-async fn main() -> Result<()> {
+async fn main() {
     let cli = Cli::parse();
+    output::set_format(cli.output);
+    if cli.dry_run {
+        tx_utils::enable_dry_run();
+    }
+    if let Err(e) = run(cli).await {
+        if output::is_json() {
+            output::emit(output::failure(e.to_json()));
+        } else {
+            eprintln!("{} {}", "❌ Error:".red().bold(), e);
+        }
+        std::process::exit(e.exit_code());
+    }
+
+    // Signing commands stop after the simulation, so the reports are their result.
+    let reports = tx_utils::dry_run_reports();
+    if !reports.is_empty() {
+        output::emit(json!({ "dry_run": reports }));
+    }
+}
+
+async fn run(cli: Cli) -> Result<()> {
+    if let Some(network) = &cli.network {
+        network::select_network(network)?;
+    }
+    if let Some(from) = cli.from {
+        config::select_account(from);
+    }
     run_command(cli.command).await
 }
 
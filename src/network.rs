@@ -0,0 +1,269 @@
+use crate::config::{load_config, NetworkConfig};
+use crate::error::{Error, Result};
+use once_cell::sync::OnceCell;
+use std::fmt;
+use std::str::FromStr;
+use subxt::backend::legacy::rpc_methods::SystemProperties;
+
+/// A named chain with its default RPC endpoint.
+///
+/// The token symbol and decimals are only used when the chain does not
+/// report them through `system_properties`.
+#[derive(Clone, Debug)]
+pub struct NetworkProfile {
+    pub name: String,
+    pub rpc_url: String,
+    pub symbol: String,
+    pub decimals: u32,
+}
+
+// (name, rpc_url, symbol, decimals)
+const BUILTIN_NETWORKS: &[(&str, &str, &str, u32)] = &[
+    ("polkadot-assethub", "wss://polkadot-asset-hub-rpc.polkadot.io", "DOT", 10),
+    ("kusama-assethub", "wss://kusama-asset-hub-rpc.polkadot.io", "KSM", 12),
+    ("paseo-assethub", "wss://asset-hub-paseo-rpc.dwellir.com", "PAS", 10),
+    ("local", "ws://127.0.0.1:9944", "UNIT", 12),
+];
+
+pub const DEFAULT_NETWORK: &str = "paseo-assethub";
+
+static NETWORK_OVERRIDE: OnceCell<NetworkProfile> = OnceCell::new();
+
+/// Look up a network profile, applying any `[networks.<name>]` section of the config file.
+///
+/// Networks that are not built in must define at least an `rpc_url` in the config.
+pub fn find_network(name: &str) -> Result<NetworkProfile> {
+    let configured = load_config()?.networks;
+    let overrides = configured.get(name);
+
+    let builtin = BUILTIN_NETWORKS
+        .iter()
+        .find(|(builtin_name, ..)| *builtin_name == name)
+        .map(|(name, rpc_url, symbol, decimals)| NetworkProfile {
+            name: name.to_string(),
+            rpc_url: rpc_url.to_string(),
+            symbol: symbol.to_string(),
+            decimals: *decimals,
+        });
+
+    let profile = match (builtin, overrides) {
+        (Some(profile), _) => profile,
+        (None, Some(NetworkConfig { rpc_url: Some(rpc_url), .. })) => NetworkProfile {
+            name: name.to_string(),
+            rpc_url: rpc_url.clone(),
+            symbol: "UNIT".to_string(),
+            decimals: 12,
+        },
+        (None, _) => {
+            let mut known: Vec<String> = BUILTIN_NETWORKS.iter().map(|(name, ..)| name.to_string()).collect();
+            known.extend(configured.keys().cloned());
+            return Err(Error::invalid_input(format!(
+                "Unknown network '{}'. Known networks: {}",
+                name,
+                known.join(", ")
+            )));
+        }
+    };
+
+    Ok(match overrides {
+        Some(overrides) => NetworkProfile {
+            rpc_url: overrides.rpc_url.clone().unwrap_or(profile.rpc_url),
+            symbol: overrides.symbol.clone().unwrap_or(profile.symbol),
+            decimals: overrides.decimals.unwrap_or(profile.decimals),
+            ..profile
+        },
+        None => profile,
+    })
+}
+
+/// Select the network for this invocation, overriding the configured one (`--network`).
+pub fn select_network(name: &str) -> Result<()> {
+    NETWORK_OVERRIDE.set(find_network(name)?).ok();
+    Ok(())
+}
+
+pub fn selected_network() -> Option<&'static NetworkProfile> {
+    NETWORK_OVERRIDE.get()
+}
+
+/// The native token of the connected chain.
+#[derive(Clone, Debug)]
+pub struct TokenInfo {
+    pub symbol: String,
+    pub decimals: u32,
+}
+
+impl TokenInfo {
+    /// Build the token info from `system_properties`, falling back to the profile's values.
+    pub fn from_properties(properties: &SystemProperties, fallback: &NetworkProfile) -> Result<Self> {
+        // Chains with several tokens report arrays; the first entry is the native token.
+        let first = |key: &str| {
+            properties.get(key).and_then(|value| match value {
+                serde_json::Value::Array(values) => values.first().cloned(),
+                other => Some(other.clone()),
+            })
+        };
+
+        let token = TokenInfo {
+            symbol: first("tokenSymbol")
+                .and_then(|v| v.as_str().map(str::to_string))
+                .unwrap_or_else(|| fallback.symbol.clone()),
+            decimals: first("tokenDecimals")
+                .and_then(|v| v.as_u64())
+                .map(|d| d.min(u32::MAX as u64) as u32)
+                .unwrap_or(fallback.decimals),
+        };
+        token.unit()?;
+        Ok(token)
+    }
+
+    /// Convert an amount of tokens into plancks, exactly.
+    pub fn to_plancks(&self, amount: &Amount) -> Result<u128> {
+        if amount.fraction.len() > self.decimals as usize {
+            return Err(Error::invalid_input(format!(
+                "{} has more decimals than {}, which has {}.",
+                amount, self.symbol, self.decimals
+            )));
+        }
+        let too_large = || Error::invalid_input(format!("{} {} is too large an amount.", amount, self.symbol));
+        let digits = |digits: &str| if digits.is_empty() { Ok(0) } else { digits.parse::<u128>().map_err(|_| too_large()) };
+
+        let fraction = digits(&format!("{:0<width$}", amount.fraction, width = self.decimals as usize))?;
+        digits(&amount.whole)?
+            .checked_mul(self.unit()?)
+            .and_then(|whole| whole.checked_add(fraction))
+            .ok_or_else(too_large)
+    }
+
+    /// Format an amount of plancks with the token's decimals, without losing precision.
+    pub fn format_plancks(&self, amount: u128) -> String {
+        // Token infos are checked when they are built, so this only happens if one was built by hand.
+        let Ok(unit) = self.unit() else {
+            return format!("{} plancks", amount);
+        };
+        let whole = amount / unit;
+        let fraction = amount % unit;
+
+        if fraction == 0 {
+            format!("{} {}", whole, self.symbol)
+        } else {
+            let fraction = format!("{:0width$}", fraction, width = self.decimals as usize);
+            format!("{}.{} {}", whole, fraction.trim_end_matches('0'), self.symbol)
+        }
+    }
+
+    /// The number of plancks in one token.
+    fn unit(&self) -> Result<u128> {
+        10u128.checked_pow(self.decimals).ok_or_else(|| {
+            Error::config(format!(
+                "{} has {} decimals, but amounts of at most 38 decimals are supported.",
+                self.symbol, self.decimals
+            ))
+        })
+    }
+}
+
+/// An amount of tokens as given on the command line, such as `1.5`.
+///
+/// It is kept as decimal digits until the token's decimals are known, so that it converts
+/// to plancks exactly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Amount {
+    whole: String,
+    fraction: String,
+}
+
+impl FromStr for Amount {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let (whole, fraction) = s.split_once('.').unwrap_or((s, ""));
+        let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if whole.is_empty() && fraction.is_empty() || !is_digits(whole) || !is_digits(fraction) {
+            return Err(format!("`{}` is not an amount of tokens; use digits with an optional decimal point, such as 1.5.", s));
+        }
+        Ok(Amount {
+            whole: whole.trim_start_matches('0').to_string(),
+            fraction: fraction.trim_end_matches('0').to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Amount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let whole = if self.whole.is_empty() { "0" } else { &self.whole };
+        if self.fraction.is_empty() {
+            write!(f, "{}", whole)
+        } else {
+            write!(f, "{}.{}", whole, self.fraction)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(decimals: u32) -> TokenInfo {
+        TokenInfo { symbol: "DOT".to_string(), decimals }
+    }
+
+    fn plancks(amount: &str, decimals: u32) -> Result<u128> {
+        token(decimals).to_plancks(&amount.parse().unwrap())
+    }
+
+    #[test]
+    fn parses_amounts() {
+        assert_eq!("1.50".parse::<Amount>().unwrap().to_string(), "1.5");
+        assert_eq!("007".parse::<Amount>().unwrap().to_string(), "7");
+        assert_eq!(".25".parse::<Amount>().unwrap().to_string(), "0.25");
+        assert_eq!("3.".parse::<Amount>().unwrap().to_string(), "3");
+        for invalid in ["", ".", "-1", "+1", "1e3", "1.2.3", "NaN", "inf", " 1"] {
+            assert!(invalid.parse::<Amount>().is_err(), "{} should be rejected", invalid);
+        }
+    }
+
+    #[test]
+    fn converts_to_plancks_exactly() {
+        assert_eq!(plancks("1.5", 10).unwrap(), 15_000_000_000);
+        assert_eq!(plancks("0.1", 18).unwrap(), 100_000_000_000_000_000);
+        assert_eq!(plancks("0.0000000001", 10).unwrap(), 1);
+        assert_eq!(plancks("123456789.123456789012345678", 18).unwrap(), 123_456_789_123_456_789_012_345_678);
+        assert_eq!(plancks("42", 0).unwrap(), 42);
+        assert_eq!(plancks("0", 12).unwrap(), 0);
+    }
+
+    #[test]
+    fn rejects_unrepresentable_amounts() {
+        assert!(plancks("0.00000000001", 10).is_err());
+        assert!(plancks("1.5", 0).is_err());
+        assert!(plancks("340282366920938463463374607431768211456", 0).is_err());
+        assert!(plancks("340282366920938463464", 18).is_err());
+        assert_eq!(plancks("340282366920938463463374607431768211455", 0).unwrap(), u128::MAX);
+        assert!(plancks("1", 39).is_err());
+    }
+
+    #[test]
+    fn formats_plancks() {
+        assert_eq!(token(10).format_plancks(15_000_000_000), "1.5 DOT");
+        assert_eq!(token(10).format_plancks(1), "0.0000000001 DOT");
+        assert_eq!(token(12).format_plancks(0), "0 DOT");
+        assert_eq!(token(0).format_plancks(42), "42 DOT");
+        assert_eq!(token(38).format_plancks(u128::MAX), "3.40282366920938463463374607431768211455 DOT");
+        assert_eq!(token(39).format_plancks(7), "7 plancks");
+    }
+
+    #[test]
+    fn rejects_more_than_38_decimals() {
+        let profile = NetworkProfile {
+            name: "local".to_string(),
+            rpc_url: "ws://127.0.0.1:9944".to_string(),
+            symbol: "UNIT".to_string(),
+            decimals: 12,
+        };
+        let properties: SystemProperties = serde_json::from_value(serde_json::json!({ "tokenDecimals": [39] })).unwrap();
+        assert!(TokenInfo::from_properties(&properties, &profile).is_err());
+        let properties: SystemProperties = serde_json::from_value(serde_json::json!({ "tokenDecimals": [12] })).unwrap();
+        assert_eq!(TokenInfo::from_properties(&properties, &profile).unwrap().decimals, 12);
+    }
+}
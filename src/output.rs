@@ -0,0 +1,95 @@
+use crate::utils::tx_utils;
+use clap::ValueEnum;
+use once_cell::sync::OnceCell;
+use serde_json::Value;
+use std::io::IsTerminal;
+
+pub use spinners::Spinners;
+
+/// How command results are written to stdout (`--output`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Colored, human readable text
+    Text,
+    /// A single JSON object per command
+    Json,
+}
+
+static FORMAT: OnceCell<OutputFormat> = OnceCell::new();
+
+/// Select the output format. Colors are disabled for JSON and whenever stdout is not a terminal.
+pub fn set_format(format: OutputFormat) {
+    FORMAT.set(format).ok();
+    if format == OutputFormat::Json || !std::io::stdout().is_terminal() {
+        colored::control::set_override(false);
+    }
+}
+
+pub fn is_json() -> bool {
+    FORMAT.get() == Some(&OutputFormat::Json)
+}
+
+/// Print a line of human readable output; suppressed in JSON mode.
+macro_rules! say {
+    ($($arg:tt)*) => {
+        if !$crate::output::is_json() {
+            println!($($arg)*);
+        }
+    };
+}
+
+/// Print the result object of a command; only used in JSON mode.
+pub fn emit(value: Value) {
+    if is_json() {
+        println!("{}", value);
+    }
+}
+
+static PARTIAL: OnceCell<Value> = OnceCell::new();
+
+/// Keep what a command completed before it failed, to be emitted along with its error.
+pub fn set_partial(value: Value) {
+    PARTIAL.set(value).ok();
+}
+
+/// The result object of a failed command: what it completed, if anything, and the error.
+pub fn failure(error: Value) -> Value {
+    let mut value = PARTIAL.get().cloned().unwrap_or_else(|| Value::Object(Default::default()));
+    value["error"] = error;
+    value
+}
+
+/// A progress spinner that is only animated in text mode on a terminal.
+///
+/// When stdout is redirected the persisted messages are still printed as plain lines,
+/// and in JSON mode nothing is printed at all.
+/// Progress is not shown at all with `--dry-run` or `tx build`, where nothing is broadcast.
+pub struct Spinner {
+    spinner: Option<spinners::Spinner>,
+    quiet: bool,
+}
+
+impl Spinner {
+    pub fn new(spinner: Spinners, message: String) -> Self {
+        let quiet = is_json() || tx_utils::is_dry_run() || tx_utils::is_build();
+        let spinner = (!quiet && std::io::stdout().is_terminal()).then(|| spinners::Spinner::new(spinner, message));
+        Spinner { spinner, quiet }
+    }
+
+    pub fn stop_and_persist(&mut self, symbol: &str, message: String) {
+        match self.spinner.take() {
+            Some(mut sp) => sp.stop_and_persist(symbol, message),
+            None if !self.quiet => println!("{} {}", symbol, message),
+            None => {}
+        }
+    }
+}
+
+// A spinner still running when a command fails would otherwise share its line with the error.
+impl Drop for Spinner {
+    fn drop(&mut self) {
+        if let Some(mut sp) = self.spinner.take() {
+            sp.stop_with_newline();
+        }
+    }
+}
@@ -0,0 +1,199 @@
+use crate::error::{Error, Result};
+use pbkdf2::pbkdf2_hmac;
+use schnorrkel::{
+    derive::{ChainCode, Derivation},
+    ExpansionMode, MiniSecretKey, SecretKey,
+};
+use sha2::Sha512;
+use std::str::FromStr;
+use subxt::{
+    tx::Signer,
+    utils::{AccountId32, MultiAddress, MultiSignature},
+    PolkadotConfig,
+};
+use subxt_signer::{
+    bip39::Mnemonic,
+    sr25519::{PublicKey, Signature},
+    DeriveJunction, ExposeSecret, SecretUri,
+};
+
+const SIGNING_CTX: &[u8] = b"substrate";
+
+/// An sr25519 keypair used to sign extrinsics.
+///
+/// This mirrors `subxt_signer::sr25519::Keypair` (same derivation and signing
+/// context), but also exposes the expanded secret key so that it can be
+/// stored in, and restored from, a polkadot-js compatible JSON keystore.
+#[derive(Clone)]
+pub struct Keypair(schnorrkel::Keypair);
+
+impl Keypair {
+    /// Create a keypair from a secret URI such as `"<mnemonic>//hard/soft///password"`.
+    pub fn from_uri(uri: &SecretUri) -> Result<Self> {
+        let SecretUri { junctions, phrase, password } = uri;
+
+        // Hex phrases are used directly as the seed; anything else must be a BIP-39 mnemonic.
+        let key = if let Some(hex_str) = phrase.expose_secret().strip_prefix("0x") {
+            let seed = hex::decode(hex_str).map_err(|e| Error::invalid_input(format!("Invalid hex seed: {}", e)))?;
+            Self::from_seed(&seed)?
+        } else {
+            let mnemonic = Mnemonic::from_str(phrase.expose_secret()).map_err(Error::invalid_input)?;
+            let password = password.as_ref().map(|p| p.expose_secret().as_str());
+            Self::from_phrase(&mnemonic, password)?
+        };
+
+        Ok(key.derive(junctions.iter().copied()))
+    }
+
+    /// Create a keypair from a BIP-39 mnemonic and optional password.
+    pub fn from_phrase(mnemonic: &Mnemonic, password: Option<&str>) -> Result<Self> {
+        let (entropy, len) = mnemonic.to_entropy_array();
+        let salt = format!("mnemonic{}", password.unwrap_or(""));
+
+        let mut seed = [0u8; 64];
+        pbkdf2_hmac::<Sha512>(&entropy[..len], salt.as_bytes(), 2048, &mut seed);
+
+        Self::from_seed(&seed[..32])
+    }
+
+    /// Restore a keypair from a 64 byte secret key in ed25519 format, as stored by polkadot-js.
+    pub fn from_ed25519_bytes(secret: &[u8]) -> Result<Self> {
+        let secret = SecretKey::from_ed25519_bytes(secret)
+            .map_err(|e| Error::config(format!("Invalid sr25519 secret key: {}", e)))?;
+        Ok(Keypair(secret.to_keypair()))
+    }
+
+    /// The 64 byte secret key in ed25519 format, as stored by polkadot-js.
+    pub fn to_ed25519_bytes(&self) -> [u8; 64] {
+        self.0.secret.to_ed25519_bytes()
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey(self.0.public.to_bytes())
+    }
+
+    pub fn sign(&self, message: &[u8]) -> Signature {
+        let context = schnorrkel::signing_context(SIGNING_CTX);
+        Signature(self.0.sign(context.bytes(message)).to_bytes())
+    }
+
+    fn from_seed(seed: &[u8]) -> Result<Self> {
+        let keypair = MiniSecretKey::from_bytes(seed)
+            .map_err(|e| Error::invalid_input(format!("Invalid seed: {}", e)))?
+            .expand_to_keypair(ExpansionMode::Ed25519);
+        Ok(Keypair(keypair))
+    }
+
+    fn derive<Js: IntoIterator<Item = DeriveJunction>>(&self, junctions: Js) -> Self {
+        let init = self.0.secret.clone();
+        let result = junctions.into_iter().fold(init, |acc, junction| match junction {
+            DeriveJunction::Soft(cc) => acc.derived_key_simple(ChainCode(cc), []).0,
+            DeriveJunction::Hard(cc) => acc
+                .hard_derive_mini_secret_key(Some(ChainCode(cc)), b"")
+                .0
+                .expand(ExpansionMode::Ed25519),
+        });
+        Keypair(result.into())
+    }
+}
+
+/// Check a signature made with `Keypair::sign` by the owner of `public_key`.
+pub fn verify(public_key: &PublicKey, message: &[u8], signature: &Signature) -> bool {
+    let context = schnorrkel::signing_context(SIGNING_CTX);
+    match (schnorrkel::PublicKey::from_bytes(&public_key.0), schnorrkel::Signature::from_bytes(&signature.0)) {
+        (Ok(public_key), Ok(signature)) => public_key.verify(context.bytes(message), &signature).is_ok(),
+        _ => false,
+    }
+}
+
+/// The account that signs the extrinsics of a command.
+///
+/// `tx build` only needs the address of the account to look up its nonce, so its keystore is
+/// not unlocked; the payload is signed later, possibly on another machine, with `tx sign`.
+pub enum AccountSigner {
+    Keypair(Box<Keypair>),
+    Address(AccountId32),
+}
+
+impl AccountSigner {
+    pub fn public_key(&self) -> PublicKey {
+        match self {
+            AccountSigner::Keypair(keypair) => keypair.public_key(),
+            AccountSigner::Address(account) => PublicKey(account.0),
+        }
+    }
+
+    pub fn account_id(&self) -> AccountId32 {
+        self.public_key().into()
+    }
+
+    /// The keypair to sign with; fails if only the address of the account was loaded.
+    pub fn keypair(&self) -> Result<&Keypair> {
+        match self {
+            AccountSigner::Keypair(keypair) => Ok(keypair.as_ref()),
+            AccountSigner::Address(account) => Err(Error::config(format!(
+                "The keypair of {} is not loaded; only `tx build` can use an address alone.",
+                account
+            ))),
+        }
+    }
+}
+
+impl Signer<PolkadotConfig> for Keypair {
+    fn account_id(&self) -> AccountId32 {
+        self.public_key().into()
+    }
+
+    fn address(&self) -> MultiAddress<AccountId32, ()> {
+        self.public_key().into()
+    }
+
+    fn sign(&self, signer_payload: &[u8]) -> MultiSignature {
+        Keypair::sign(self, signer_payload).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use subxt_signer::sr25519;
+
+    const PHRASE: &str = "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+
+    #[test]
+    fn derives_like_subxt_signer() {
+        assert_eq!(
+            Keypair::from_uri(&SecretUri::from_str("//Alice").unwrap()).unwrap().public_key().0,
+            sr25519::dev::alice().public_key().0
+        );
+        for uri in ["//Bob//stash", "//Alice/soft/0", &format!("{}//polkadot/0///secret", PHRASE)] {
+            let uri = SecretUri::from_str(uri).unwrap();
+            assert_eq!(
+                Keypair::from_uri(&uri).unwrap().public_key().0,
+                sr25519::Keypair::from_uri(&uri).unwrap().public_key().0,
+                "{:?}",
+                uri.junctions
+            );
+        }
+    }
+
+    #[test]
+    fn verifies_signatures() {
+        let keypair = Keypair::from_uri(&SecretUri::from_str("//Alice").unwrap()).unwrap();
+        let signature = keypair.sign(b"polkacli");
+        assert!(verify(&keypair.public_key(), b"polkacli", &signature));
+        assert!(sr25519::verify(&signature, b"polkacli", &keypair.public_key()));
+        assert!(!verify(&keypair.public_key(), b"polkacla", &signature));
+        assert!(!verify(&sr25519::dev::bob().public_key(), b"polkacli", &signature));
+
+        let signature = sr25519::dev::alice().sign(b"polkacli");
+        assert!(verify(&keypair.public_key(), b"polkacli", &signature));
+    }
+
+    #[test]
+    fn ed25519_bytes_round_trip() {
+        let keypair = Keypair::from_uri(&SecretUri::from_str("//Alice").unwrap()).unwrap();
+        let restored = Keypair::from_ed25519_bytes(&keypair.to_ed25519_bytes()).unwrap();
+        assert_eq!(restored.public_key().0, keypair.public_key().0);
+    }
+}
@@ -0,0 +1,9 @@
+use crate::error::{Error, Result};
+use std::str::FromStr;
+use subxt::utils::AccountId32;
+
+/// Parse an SS58 address given on the command line.
+pub fn parse_address(address: &str) -> Result<AccountId32> {
+    AccountId32::from_str(address)
+        .map_err(|e| Error::invalid_input(format!("Invalid address '{}': {}", address, e)))
+}
@@ -0,0 +1,146 @@
+use crate::client::get_client;
+use crate::commands::assethub;
+use crate::error::{Error, Result};
+use crate::utils::tx_utils::RuntimeCall;
+use clap::ValueEnum;
+use serde_json::{json, Value};
+use subxt::ext::codec::Decode;
+use subxt::utils::AccountId32;
+
+use assethub::runtime_types::bounded_collections::bounded_vec::BoundedVec;
+use assethub::runtime_types::pallet_nfts::types::AttributeNamespace;
+
+/// Who an attribute belongs to (`--namespace`). The `Pallet` namespace is reserved for the pallet itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum NamespaceArg {
+    /// Set by the collection owner
+    CollectionOwner,
+    /// Set by the item owner
+    ItemOwner,
+    /// Set by the signing account, once the item owner approved it
+    Account,
+}
+
+impl NamespaceArg {
+    /// The namespace of an attribute set by `signer`.
+    pub fn namespace(self, signer: AccountId32) -> AttributeNamespace<AccountId32> {
+        match self {
+            NamespaceArg::CollectionOwner => AttributeNamespace::CollectionOwner,
+            NamespaceArg::ItemOwner => AttributeNamespace::ItemOwner,
+            NamespaceArg::Account => AttributeNamespace::Account(signer),
+        }
+    }
+}
+
+/// An attribute of a collection or item, as stored on chain.
+pub struct Attribute {
+    pub namespace: AttributeNamespace<AccountId32>,
+    pub key: Vec<u8>,
+    pub value: Vec<u8>,
+}
+
+impl Attribute {
+    pub fn to_json(&self) -> Value {
+        let (namespace, account) = namespace_parts(&self.namespace);
+        json!({
+            "namespace": namespace,
+            "account": account,
+            "key": String::from_utf8_lossy(&self.key),
+            "value": String::from_utf8_lossy(&self.value),
+        })
+    }
+}
+
+/// The name of a namespace, and the account of the `Account` namespace.
+pub fn namespace_parts(namespace: &AttributeNamespace<AccountId32>) -> (&'static str, Option<String>) {
+    match namespace {
+        AttributeNamespace::Pallet => ("pallet", None),
+        AttributeNamespace::CollectionOwner => ("collection-owner", None),
+        AttributeNamespace::ItemOwner => ("item-owner", None),
+        AttributeNamespace::Account(account) => ("account", Some(account.to_string())),
+    }
+}
+
+/// Every attribute of an item, in all namespaces.
+pub async fn fetch_item_attributes(collection_id: u32, item_id: u32) -> Result<Vec<Attribute>> {
+    let api = get_client().await?;
+    let address = assethub::storage().nfts().attribute_iter2(collection_id, Some(item_id));
+    let mut entries = api.storage().at_latest().await?.iter(address).await?;
+    let mut attributes = Vec::new();
+    while let Some(entry) = entries.next().await {
+        let entry = entry?;
+        let (namespace, key) = decode_attribute_key(&entry.key_bytes)
+            .ok_or_else(|| Error::Other("Undecodable attribute storage key.".to_string()))?;
+        attributes.push(Attribute { namespace, key, value: entry.value.0 .0 });
+    }
+    Ok(attributes)
+}
+
+// The namespace and key at the end of an `Nfts::Attribute` storage key. Each part is hashed
+// with Blake2_128Concat, so it follows its 16 byte hash: the collection, the optional item,
+// then the namespace and the key.
+fn decode_attribute_key(key_bytes: &[u8]) -> Option<(AttributeNamespace<AccountId32>, Vec<u8>)> {
+    let mut input = key_bytes.get(32 + 16 + 4 + 16..)?;
+    Option::<u32>::decode(&mut input).ok()?;
+    input = input.get(16..)?;
+    let namespace = AttributeNamespace::<AccountId32>::decode(&mut input).ok()?;
+    input = input.get(16..)?;
+    let key = BoundedVec::<u8>::decode(&mut input).ok()?;
+    Some((namespace, key.0))
+}
+
+/// Calls setting an item's on-chain attributes in the collection owner namespace from the
+/// `attributes` array of its JSON metadata.
+pub fn attribute_calls(collection_id: u32, item_id: u32, json: &Value) -> Result<Vec<RuntimeCall>> {
+    use assethub::runtime_types::pallet_nfts::pallet::Call;
+
+    Ok(metadata_attributes(json)?
+        .into_iter()
+        .map(|(key, value)| {
+            RuntimeCall::Nfts(Call::set_attribute {
+                collection: collection_id,
+                maybe_item: Some(item_id),
+                namespace: AttributeNamespace::CollectionOwner,
+                key: BoundedVec(key.into_bytes()),
+                value: BoundedVec(value.into_bytes()),
+            })
+        })
+        .collect())
+}
+
+/// The keys and values of the `attributes` array of JSON metadata, in the usual
+/// `{"trait_type": ..., "value": ...}` form.
+pub fn metadata_attributes(json: &Value) -> Result<Vec<(String, String)>> {
+    let Some(attributes) = json.get("attributes") else {
+        return Ok(Vec::new());
+    };
+    let attributes = attributes
+        .as_array()
+        .ok_or_else(|| Error::invalid_input("The `attributes` of the JSON metadata must be an array."))?;
+    attributes
+        .iter()
+        .map(|attribute| {
+            let key = attribute.get("trait_type").and_then(Value::as_str);
+            let value = match attribute.get("value") {
+                Some(Value::String(value)) => Some(value.clone()),
+                Some(value @ (Value::Number(_) | Value::Bool(_))) => Some(value.to_string()),
+                _ => None,
+            };
+            let (Some(key), Some(value)) = (key, value) else {
+                return Err(Error::invalid_input(format!(
+                    "Attribute {} needs a string `trait_type` and a string, number or boolean `value`.",
+                    attribute
+                )));
+            };
+            Ok((key.to_string(), value))
+        })
+        .collect()
+}
+
+/// Parse a `KEY=VALUE` attribute given on the command line.
+pub fn parse_key_value(s: &str) -> std::result::Result<(String, String), String> {
+    match s.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("`{}` is not an attribute; use KEY=VALUE.", s)),
+    }
+}
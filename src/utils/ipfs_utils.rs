@@ -1,6 +1,17 @@
-use crate::error::Result;
+use crate::error::{Error, Result};
+use once_cell::sync::Lazy;
 use reqwest::Client;
-use std::error::Error;
+use serde_json::Value;
+use std::time::Duration;
+
+// Shared by metadata lookups, which give up quickly on a slow gateway instead of stalling a command.
+static FETCH_CLIENT: Lazy<Client> = Lazy::new(|| {
+    Client::builder()
+        .connect_timeout(Duration::from_secs(5))
+        .timeout(Duration::from_secs(10))
+        .build()
+        .expect("a client with timeouts only")
+});
 
 pub async fn pin_to_ipfs(data: &[u8]) -> Result<String> {
     let pinata_jwt = crate::config::load_pinata_jwt_from_config()?;
@@ -13,22 +24,21 @@ pub async fn pin_to_ipfs(data: &[u8]) -> Result<String> {
             .part("file", reqwest::multipart::Part::bytes(data.to_vec()).file_name("data"));
 
         let response = client
-            .post(&format!("{}/pinning/pinFileToIPFS", pinata_gateway))
+            .post(format!("{}/pinning/pinFileToIPFS", pinata_gateway))
             .bearer_auth(jwt)  // Use JWT for authorization
             .multipart(form)
             .send()
             .await
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+            .map_err(|e| Error::ipfs(format!("Failed to reach IPFS: {}", e)))?;
 
         if !response.status().is_success() {
-            return Err(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
+            return Err(Error::ipfs(
                 format!("Failed to pin to IPFS via Pinata: {:?}", response.text().await),
-            )));
+            ));
         }
 
-        let pin_response: serde_json::Value = response.json().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
-        let ipfs_hash = pin_response["IpfsHash"].as_str().ok_or("Failed to parse IPFS hash from Pinata response")?;
+        let pin_response: serde_json::Value = response.json().await.map_err(|e| Error::ipfs(format!("Invalid response from Pinata: {}", e)))?;
+        let ipfs_hash = pin_response["IpfsHash"].as_str().ok_or_else(|| Error::ipfs("Failed to parse IPFS hash from Pinata response"))?;
 
         Ok(format!("ipfs://{}", ipfs_hash))
     } else {
@@ -37,10 +47,45 @@ pub async fn pin_to_ipfs(data: &[u8]) -> Result<String> {
             .body(data.to_vec())
             .send()
             .await
-            .map_err(|e| Box::new(e) as Box<dyn Error>)?;
+            .map_err(|e| Error::ipfs(format!("Failed to reach IPFS: {}", e)))?;
 
-        let ipfs_hash = response.text().await.map_err(|e| Box::new(e) as Box<dyn Error>)?;
+        let ipfs_hash = response.text().await.map_err(|e| Error::ipfs(format!("Invalid response from IPFS: {}", e)))?;
         Ok(format!("ipfs://{}", ipfs_hash))
     }
 }
 
+// Check if the data is an IPFS link
+pub fn is_ipfs_link(data: &str) -> bool {
+    data.starts_with("ipfs://")
+}
+
+// Convert an IPFS link to an HTTP URL
+pub fn ipfs_to_http_url(ipfs_link: &str) -> String {
+    ipfs_link.replace("ipfs://", "https://ipfs.io/ipfs/")
+}
+
+/// Fetch the JSON document behind an IPFS link.
+pub async fn fetch_json(ipfs_link: &str) -> Result<Value> {
+    let response = FETCH_CLIENT
+        .get(ipfs_to_http_url(ipfs_link))
+        .send()
+        .await
+        .map_err(|e| Error::ipfs(format!("Failed to reach IPFS: {}", e)))?
+        .error_for_status()?;
+    response
+        .json()
+        .await
+        .map_err(|e| Error::ipfs(format!("Invalid JSON at {}: {}", ipfs_link, e)))
+}
+
+/// The `name` in JSON metadata stored on chain or behind an IPFS link.
+///
+/// Metadata that cannot be resolved gives `None`, so a slow gateway never fails a listing.
+pub async fn metadata_name(metadata: &str) -> Option<String> {
+    let json: Value = if is_ipfs_link(metadata) {
+        fetch_json(metadata).await.ok()?
+    } else {
+        serde_json::from_str(metadata).ok()?
+    };
+    json.get("name")?.as_str().map(str::to_string)
+}
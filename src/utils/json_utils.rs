@@ -1,29 +1,27 @@
-use crate::error::Result;
+use crate::error::{Error, Result};
 use serde_json::Value;
 use std::fs::File;
 use std::path::{Path, PathBuf};
-use std::error::Error;
 
 /// Load a JSON file from the given path and deserialize it into a `serde_json::Value`.
 /// Provides detailed error messages if the file cannot be opened or if the JSON is invalid.
 pub fn load_json_from_file(path: &Path) -> Result<Value> {
     // Attempt to open the file, providing a specific error message if it fails.
     let file = File::open(path).map_err(|e| {
-        format!(
+        Error::invalid_input(format!(
             "Failed to open JSON file at {}: {}",
             path.display(),
-            e.description()
-        )
+            e
+        ))
     })?;
     
     // Attempt to parse the JSON from the file, providing a specific error message if it fails.
     serde_json::from_reader(file).map_err(|e| {
-        format!(
+        Error::invalid_input(format!(
             "Failed to parse JSON from file at {}: {}",
             path.display(),
-            e.description()
-        )
-        .into()
+            e
+        ))
     })
 }
 
@@ -34,18 +32,18 @@ pub fn load_json_from_file(path: &Path) -> Result<Value> {
 pub fn find_image_for_json(json_path: &Path) -> Result<PathBuf> {
     // Ensure the JSON file has a parent directory.
     let parent_dir = json_path.parent().ok_or_else(|| {
-        format!(
+        Error::invalid_input(format!(
             "Failed to determine parent directory for JSON file at {}",
             json_path.display()
-        )
+        ))
     })?;
     
     // Ensure the JSON file has a valid stem (filename without extension).
     let json_stem = json_path.file_stem().ok_or_else(|| {
-        format!(
+        Error::invalid_input(format!(
             "Failed to extract file stem from JSON file name at {}",
             json_path.display()
-        )
+        ))
     })?.to_string_lossy();
     
     // Check for image files with the same stem and various extensions.
@@ -57,9 +55,9 @@ pub fn find_image_for_json(json_path: &Path) -> Result<PathBuf> {
     }
 
     // If no image is found, return an error with a detailed message.
-    Err(format!(
+    Err(Error::invalid_input(format!(
         "No matching image found for JSON file at {}. Looked for .jpg, .jpeg, and .png files with the same name.",
         json_path.display()
-    ).into())
+    )))
 }
 
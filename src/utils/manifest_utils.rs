@@ -0,0 +1,324 @@
+use crate::error::{Error, Result};
+use crate::utils::json_utils;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// An item to mint in bulk: its ID and the files to pin as its metadata.
+#[derive(Debug, Clone)]
+pub struct ManifestItem {
+    pub item_id: u32,
+    pub json: Option<PathBuf>,
+    pub image: Option<PathBuf>,
+}
+
+/// The items to mint into a collection, ordered by item ID.
+pub struct Manifest {
+    pub items: Vec<ManifestItem>,
+}
+
+#[derive(Deserialize)]
+struct ManifestEntry {
+    item_id: u32,
+    json: Option<PathBuf>,
+    image: Option<PathBuf>,
+}
+
+impl Manifest {
+    /// Load a manifest from a CSV file with an `item_id,json,image` header, or from a JSON array
+    /// of `{"item_id", "json", "image"}` objects. Relative paths are resolved against the manifest's folder.
+    pub fn load(path: &Path) -> Result<Self> {
+        let base = path.parent().unwrap_or_else(|| Path::new("."));
+        let entries = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => serde_json::from_value::<Vec<ManifestEntry>>(json_utils::load_json_from_file(path)?)
+                .map_err(|e| Error::invalid_input(format!("Invalid manifest {}: {}", path.display(), e)))?,
+            Some("csv") => parse_csv(&std::fs::read_to_string(path)?)
+                .map_err(|e| Error::invalid_input(format!("Invalid manifest {}: {}", path.display(), e)))?,
+            _ => return Err(Error::invalid_input(format!("Manifest {} must be a .csv or .json file.", path.display()))),
+        };
+
+        let items = entries
+            .into_iter()
+            .map(|entry| ManifestItem {
+                item_id: entry.item_id,
+                json: entry.json.map(|json| base.join(json)),
+                image: entry.image.map(|image| base.join(image)),
+            })
+            .collect();
+        Self::new(items)
+    }
+
+    /// Build a manifest from a folder of JSON metadata files, sorted by file name and numbered from 0.
+    /// Images are looked up next to each JSON file, or in `image_folder` if given.
+    pub fn from_folder(json_folder: &Path, image_folder: Option<&Path>) -> Result<Self> {
+        let mut json_paths: Vec<PathBuf> = json_folder
+            .read_dir()?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::result::Result<_, _>>()?;
+        json_paths.retain(|path| path.extension().is_some_and(|ext| ext == "json"));
+        json_paths.sort();
+
+        let mut items = Vec::new();
+        for (item_id, json_path) in json_paths.into_iter().enumerate() {
+            let image = match image_folder {
+                Some(image_folder) => {
+                    let image = json_utils::find_image_for_json(&json_path).ok().or_else(|| {
+                        let file_stem = json_path.file_stem()?.to_str()?;
+                        ["jpg", "jpeg", "png"]
+                            .iter()
+                            .map(|ext| image_folder.join(format!("{}.{}", file_stem, ext)))
+                            .find(|path| path.exists())
+                    });
+                    Some(image.ok_or_else(|| {
+                        Error::invalid_input(format!("No image found or provided for {}.", json_path.display()))
+                    })?)
+                }
+                None => None,
+            };
+            items.push(ManifestItem { item_id: item_id as u32, json: Some(json_path), image });
+        }
+        Self::new(items)
+    }
+
+    fn new(mut items: Vec<ManifestItem>) -> Result<Self> {
+        items.sort_by_key(|item| item.item_id);
+        if let Some(pair) = items.windows(2).find(|pair| pair[0].item_id == pair[1].item_id) {
+            return Err(Error::invalid_input(format!("Item ID {} appears more than once in the manifest.", pair[0].item_id)));
+        }
+        Ok(Manifest { items })
+    }
+}
+
+// Minimal CSV: a header naming the columns, then one item per line. Quoted fields are not supported.
+fn parse_csv(contents: &str) -> std::result::Result<Vec<ManifestEntry>, String> {
+    let mut lines = contents.lines().enumerate().filter(|(_, line)| !line.trim().is_empty());
+    let (_, header) = lines.next().ok_or("the file is empty")?;
+    let columns: Vec<&str> = header.split(',').map(str::trim).collect();
+    let column = |name: &str| columns.iter().position(|column| *column == name);
+    let item_id_column = column("item_id").ok_or("the header has no item_id column")?;
+    let (json_column, image_column) = (column("json"), column("image"));
+
+    lines
+        .map(|(number, line)| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let field = |index: Option<usize>| {
+                index
+                    .and_then(|index| fields.get(index))
+                    .filter(|field| !field.is_empty())
+                    .map(PathBuf::from)
+            };
+            let item_id = fields
+                .get(item_id_column)
+                .and_then(|id| id.parse().ok())
+                .ok_or_else(|| format!("line {} has no valid item_id", number + 1))?;
+            Ok(ManifestEntry { item_id, json: field(json_column), image: field(image_column) })
+        })
+        .collect()
+}
+
+/// A step of a bulk mint, recorded in the journal once it has completed.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "step", rename_all = "snake_case")]
+pub enum JournalEntry {
+    Collection { collection_id: u32, extrinsic_hash: String },
+    CollectionMetadataSet { metadata: String, extrinsic_hash: String },
+    Pinned { item_id: u32, image: String, metadata: String },
+    Minted { item_id: u32, extrinsic_hash: String },
+    MetadataSet { item_id: u32, extrinsic_hash: String },
+    /// Steps found done on chain when resuming, whose extrinsics were never confirmed.
+    FoundOnChain { item_id: u32, minted: bool, metadata_set: bool },
+}
+
+/// What has been done for an item so far.
+#[derive(Default, Serialize)]
+pub struct ItemProgress {
+    pub image: Option<String>,
+    pub metadata: Option<String>,
+    pub mint_tx: Option<String>,
+    pub metadata_tx: Option<String>,
+    pub minted: bool,
+    pub metadata_set: bool,
+}
+
+/// The progress of a bulk mint, kept in a JSON Lines file with one completed step per line
+/// so that an interrupted run can be resumed with `--resume`.
+pub struct Journal {
+    file: Option<File>,
+    pub collection_id: Option<u32>,
+    pub collection_metadata_tx: Option<String>,
+    pub items: BTreeMap<u32, ItemProgress>,
+}
+
+impl Journal {
+    /// Start a new journal, refusing to overwrite the journal of an earlier run.
+    pub fn create(path: &Path) -> Result<Self> {
+        let file = OpenOptions::new().append(true).create_new(true).open(path).map_err(|e| {
+            if e.kind() == std::io::ErrorKind::AlreadyExists {
+                Error::invalid_input(format!(
+                    "The journal {} of an earlier run exists. Pass --resume to continue it, or remove it to start over.",
+                    path.display()
+                ))
+            } else {
+                e.into()
+            }
+        })?;
+        Ok(Journal { file: Some(file), collection_id: None, collection_metadata_tx: None, items: BTreeMap::new() })
+    }
+
+    /// A journal that is not written to disk, for `--dry-run` and `tx build`.
+    pub fn in_memory() -> Self {
+        Journal { file: None, collection_id: None, collection_metadata_tx: None, items: BTreeMap::new() }
+    }
+
+    /// Reopen the journal of an interrupted run and replay its steps.
+    pub fn resume(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| Error::invalid_input(format!("Cannot open the journal {}: {}", path.display(), e)))?;
+        let mut file = OpenOptions::new().append(true).open(path)?;
+        // A run killed while writing leaves a partial last line; its step is simply redone.
+        if !contents.is_empty() && !contents.ends_with('\n') {
+            file.write_all(b"\n")?;
+        }
+
+        let mut journal = Journal { file: Some(file), collection_id: None, collection_metadata_tx: None, items: BTreeMap::new() };
+        for (number, line) in contents.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let Ok(entry) = serde_json::from_str::<JournalEntry>(line) else {
+                say!("⚠️ Ignoring unreadable line {} of the journal {}.", number + 1, path.display());
+                continue;
+            };
+            journal.apply(entry);
+        }
+        Ok(journal)
+    }
+
+    /// Append a completed step to the journal.
+    pub fn record(&mut self, entry: JournalEntry) -> Result<()> {
+        if let Some(file) = &mut self.file {
+            let mut line = serde_json::to_string(&entry)?;
+            line.push('\n');
+            file.write_all(line.as_bytes())?;
+            file.sync_data()?;
+        }
+        self.apply(entry);
+        Ok(())
+    }
+
+    fn apply(&mut self, entry: JournalEntry) {
+        match entry {
+            JournalEntry::Collection { collection_id, .. } => self.collection_id = Some(collection_id),
+            JournalEntry::CollectionMetadataSet { extrinsic_hash, .. } => self.collection_metadata_tx = Some(extrinsic_hash),
+            JournalEntry::Pinned { item_id, image, metadata } => {
+                let item = self.items.entry(item_id).or_default();
+                item.image = Some(image);
+                item.metadata = Some(metadata);
+            }
+            JournalEntry::Minted { item_id, extrinsic_hash } => {
+                let item = self.items.entry(item_id).or_default();
+                item.mint_tx = Some(extrinsic_hash);
+                item.minted = true;
+            }
+            JournalEntry::MetadataSet { item_id, extrinsic_hash } => {
+                let item = self.items.entry(item_id).or_default();
+                item.metadata_tx = Some(extrinsic_hash);
+                item.metadata_set = true;
+            }
+            JournalEntry::FoundOnChain { item_id, minted, metadata_set } => {
+                let item = self.items.entry(item_id).or_default();
+                item.minted |= minted;
+                item.metadata_set |= metadata_set;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("polkacli-manifest-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn parses_csv_manifest() {
+        let dir = test_dir("csv");
+        let path = dir.join("items.csv");
+        std::fs::write(&path, "image, item_id, json\n\nb.png,2,b.json\n, 1 ,a.json\n,3,\n").unwrap();
+
+        let manifest = Manifest::load(&path).unwrap();
+        let ids: Vec<u32> = manifest.items.iter().map(|item| item.item_id).collect();
+        assert_eq!(ids, [1, 2, 3]);
+        assert_eq!(manifest.items[0].json, Some(dir.join("a.json")));
+        assert_eq!(manifest.items[0].image, None);
+        assert_eq!(manifest.items[1].image, Some(dir.join("b.png")));
+        assert_eq!(manifest.items[2].json, None);
+
+        assert!(parse_csv("json,image\na.json,a.png\n").is_err());
+        assert!(parse_csv("item_id,json\nfirst,a.json\n").is_err());
+        assert!(parse_csv("").is_err());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn parses_json_manifest() {
+        let dir = test_dir("json");
+        let path = dir.join("items.json");
+        std::fs::write(&path, r#"[{"item_id": 7, "json": "meta/7.json", "image": "/images/7.png"}, {"item_id": 5}]"#).unwrap();
+
+        let manifest = Manifest::load(&path).unwrap();
+        assert_eq!(manifest.items.len(), 2);
+        assert_eq!(manifest.items[0].item_id, 5);
+        assert_eq!(manifest.items[1].json, Some(dir.join("meta/7.json")));
+        assert_eq!(manifest.items[1].image, Some(PathBuf::from("/images/7.png")));
+
+        std::fs::write(&path, r#"[{"item_id": 1}, {"item_id": 1}]"#).unwrap();
+        assert!(matches!(Manifest::load(&path), Err(Error::InvalidInput(_))));
+        std::fs::write(&path, r#"[{"json": "a.json"}]"#).unwrap();
+        assert!(matches!(Manifest::load(&path), Err(Error::InvalidInput(_))));
+        assert!(Manifest::load(&dir.join("items.txt")).is_err());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn replays_journal() {
+        let dir = test_dir("journal");
+        let path = dir.join("items.csv.journal.jsonl");
+        let mut journal = Journal::create(&path).unwrap();
+        journal.record(JournalEntry::Collection { collection_id: 42, extrinsic_hash: "0x01".to_string() }).unwrap();
+        journal
+            .record(JournalEntry::Pinned { item_id: 0, image: "ipfs://image".to_string(), metadata: "ipfs://meta".to_string() })
+            .unwrap();
+        journal.record(JournalEntry::Minted { item_id: 0, extrinsic_hash: "0x02".to_string() }).unwrap();
+        journal.record(JournalEntry::FoundOnChain { item_id: 1, minted: true, metadata_set: false }).unwrap();
+        drop(journal);
+        assert!(Journal::create(&path).is_err());
+
+        // An unreadable line and a partial last line, as left by a run killed while writing.
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"not json\n{\"step\":\"metadata_set\",\"item_id\":0,").unwrap();
+        drop(file);
+
+        let mut journal = Journal::resume(&path).unwrap();
+        assert_eq!(journal.collection_id, Some(42));
+        let item = &journal.items[&0];
+        assert_eq!(item.metadata.as_deref(), Some("ipfs://meta"));
+        assert_eq!(item.mint_tx.as_deref(), Some("0x02"));
+        assert!(item.minted && !item.metadata_set);
+        assert!(journal.items[&1].minted && journal.items[&1].mint_tx.is_none());
+
+        journal.record(JournalEntry::MetadataSet { item_id: 0, extrinsic_hash: "0x03".to_string() }).unwrap();
+        drop(journal);
+        let journal = Journal::resume(&path).unwrap();
+        assert!(journal.items[&0].metadata_set);
+        assert_eq!(journal.items[&0].metadata_tx.as_deref(), Some("0x03"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -1,4 +1,10 @@
 // src/utils/mod.rs
+pub mod account_utils;
+pub mod attribute_utils;
 pub mod ipfs_utils;
 pub mod json_utils;
-
+pub mod manifest_utils;
+pub mod pipeline_utils;
+pub mod prompt_utils;
+pub mod settings_utils;
+pub mod tx_utils;
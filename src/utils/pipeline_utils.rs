@@ -0,0 +1,282 @@
+use crate::client::{get_client, get_legacy_rpc};
+use crate::commands::assethub;
+use crate::error::{Error, Result};
+use crate::signer::{AccountSigner, Keypair};
+use crate::utils::tx_utils::{self, TxResult};
+use colored::*;
+use std::collections::{BTreeSet, VecDeque};
+use std::time::Duration;
+use subxt::config::polkadot::PolkadotExtrinsicParamsBuilder;
+use subxt::tx::{Payload, SubmittableExtrinsic};
+use subxt::utils::AccountId32;
+use subxt::{OnlineClient, PolkadotConfig};
+use tokio::task::JoinSet;
+
+/// How often a transaction that left the pool without being finalized is resubmitted.
+const MAX_RESUBMISSIONS: u32 = 3;
+
+/// How long a transaction is watched before it is considered lost.
+const WATCH_TIMEOUT: Duration = Duration::from_secs(300);
+
+type Extrinsic = SubmittableExtrinsic<PolkadotConfig, OnlineClient<PolkadotConfig>>;
+
+// What became of a submitted extrinsic.
+enum Outcome {
+    // Included in a finalized block, successfully or not; its nonce is used.
+    Finalized(Result<TxResult>),
+    // Rejected by the node on submission, so it never entered the pool.
+    Rejected(subxt::Error),
+    // Accepted into the pool, then dropped, invalidated, lost track of or timed out before being finalized.
+    Lost(Error),
+}
+
+struct Job {
+    // Index of the payload, or `None` for a remark that fills a nonce gap.
+    index: Option<usize>,
+    nonce: Option<u64>,
+    // Whether an earlier submission was accepted into the pool; if so the nonce is never changed.
+    accepted: bool,
+    resubmissions: u32,
+}
+
+// Nonces of the account: the next unused one, and lower ones left unused by failed transactions.
+struct Nonces {
+    next: u64,
+    free: BTreeSet<u64>,
+}
+
+impl Nonces {
+    fn take(&mut self) -> u64 {
+        self.free.pop_first().unwrap_or_else(|| {
+            self.next += 1;
+            self.next - 1
+        })
+    }
+
+    // Skip the nonces that were used by other transactions of the account.
+    fn resync(&mut self, chain_next: u64) {
+        self.next = self.next.max(chain_next);
+        self.free.retain(|nonce| *nonce >= chain_next);
+    }
+}
+
+/// Submit extrinsics signed by one account, keeping up to `in_flight` of them on their way to
+/// finalization at the same time.
+///
+/// Nonces are assigned locally, starting at the account's next index as reported by the node.
+/// A transaction rejected because another one took its nonce ("Priority is too low", stale) is
+/// signed again with a new nonce. A transaction that was dropped after entering the pool is only
+/// resubmitted under its original nonce, so that it can never be included twice. Nonces left
+/// unused by failed transactions are filled with empty remarks, so that later ones are not stuck.
+/// A transaction not finalized within `WATCH_TIMEOUT` counts as dropped.
+///
+/// `on_result` is called with the index of each payload as soon as its outcome is known.
+/// If it fails, or a nonce gap cannot be filled, nothing more is submitted and the error is
+/// returned once the transactions in flight have been reported.
+/// With `--dry-run` or under `tx build` only the first payload is handled, and `false` is returned.
+pub async fn submit_concurrently<Call, F>(
+    payloads: &[Call],
+    signer: &AccountSigner,
+    in_flight: usize,
+    mut on_result: F,
+) -> Result<bool>
+where
+    Call: Payload,
+    F: FnMut(usize, Result<TxResult>) -> Result<()>,
+{
+    if tx_utils::is_dry_run() || tx_utils::is_build() {
+        if let Some(payload) = payloads.first() {
+            tx_utils::submit(payload, signer).await?;
+        }
+        return Ok(false);
+    }
+
+    let api = get_client().await?;
+    let keypair = signer.keypair()?;
+    let account = signer.account_id();
+    let mut nonces = Nonces {
+        next: get_legacy_rpc().await?.system_account_next_index(&account).await?,
+        free: BTreeSet::new(),
+    };
+
+    let mut queue: VecDeque<Job> = (0..payloads.len())
+        .map(|index| Job { index: Some(index), nonce: None, accepted: false, resubmissions: 0 })
+        .collect();
+    let mut running = JoinSet::new();
+    let mut submitted = BTreeSet::new();
+    // Once something fails for good nothing new is submitted, but the transactions in flight are
+    // still awaited, so that `on_result` sees every outcome before the error is returned.
+    let mut failure: Option<Error> = None;
+
+    loop {
+        while failure.is_none() && running.len() < in_flight.max(1) {
+            let Some(mut job) = queue.pop_front() else {
+                break;
+            };
+            let nonce = *job.nonce.get_or_insert_with(|| nonces.take());
+            let signed = match job.index {
+                Some(index) => sign(api, &payloads[index], keypair, nonce),
+                None => sign(api, &assethub::tx().system().remark(Vec::new()), keypair, nonce),
+            };
+            let extrinsic = match signed {
+                Ok(extrinsic) => extrinsic,
+                Err(e) => {
+                    failure = Some(e);
+                    break;
+                }
+            };
+            submitted.insert(nonce);
+            running.spawn(async move {
+                let outcome = tokio::time::timeout(WATCH_TIMEOUT, watch(extrinsic)).await.unwrap_or_else(|_| {
+                    Outcome::Lost(Error::Other(format!("not finalized within {} seconds", WATCH_TIMEOUT.as_secs())))
+                });
+                (job, outcome)
+            });
+        }
+
+        // Transactions above an unused nonce would wait in the pool forever.
+        if failure.is_none() && queue.is_empty() {
+            if let Some(highest) = submitted.last().copied() {
+                let gaps: Vec<u64> = nonces.free.iter().copied().filter(|nonce| *nonce < highest).collect();
+                for nonce in gaps {
+                    nonces.free.remove(&nonce);
+                    queue.push_back(Job { index: None, nonce: Some(nonce), accepted: false, resubmissions: 0 });
+                }
+                if !queue.is_empty() {
+                    continue;
+                }
+            }
+        }
+
+        let Some(joined) = running.join_next().await else {
+            break;
+        };
+        let (job, outcome) = match joined {
+            Ok(joined) => joined,
+            Err(e) => {
+                failure.get_or_insert(Error::Other(format!("Transaction watcher failed: {}", e)));
+                continue;
+            }
+        };
+        submitted.remove(&job.nonce.expect("submitted jobs have a nonce"));
+        let handled = handle_outcome(job, outcome, &mut nonces, &mut queue, &mut on_result, &account).await;
+        if let Err(e) = handled {
+            failure.get_or_insert(e);
+        }
+    }
+    match failure {
+        Some(e) => Err(e),
+        None => Ok(true),
+    }
+}
+
+// Report the outcome of a job, or queue it again.
+async fn handle_outcome<F>(
+    mut job: Job,
+    outcome: Outcome,
+    nonces: &mut Nonces,
+    queue: &mut VecDeque<Job>,
+    on_result: &mut F,
+    account: &AccountId32,
+) -> Result<()>
+where
+    F: FnMut(usize, Result<TxResult>) -> Result<()>,
+{
+    let nonce = job.nonce.expect("submitted jobs have a nonce");
+    match (outcome, job.index) {
+        (Outcome::Finalized(result), Some(index)) => on_result(index, result),
+        (Outcome::Finalized(_), None) => Ok(()),
+        (Outcome::Rejected(e), Some(_)) if nonce_taken(&e) && !job.accepted => {
+            // It never entered the pool, so signing it again with a new nonce is safe.
+            nonces.resync(get_legacy_rpc().await?.system_account_next_index(account).await?);
+            job.nonce = None;
+            queue.push_front(job);
+            Ok(())
+        }
+        (Outcome::Rejected(e), Some(index)) if nonce_taken(&e) => on_result(
+            index,
+            Err(Error::Other(format!(
+                "The transaction with nonce {} was dropped from the pool, and its nonce has been used since. \
+                 It is not signed again, since it may have been included after all.",
+                nonce
+            ))),
+        ),
+        // Another transaction of the account took the nonce of the remark, so there is no gap left.
+        (Outcome::Rejected(e), None) if nonce_taken(&e) => Ok(()),
+        (Outcome::Rejected(e), Some(index)) => {
+            nonces.free.insert(nonce);
+            on_result(index, Err(e.into()))
+        }
+        (Outcome::Rejected(e), None) if job.resubmissions < MAX_RESUBMISSIONS => {
+            say!("{}", format!("⚠️ Remark filling nonce {} was rejected ({}); submitting it again.", nonce, e).yellow());
+            job.resubmissions += 1;
+            queue.push_front(job);
+            Ok(())
+        }
+        (Outcome::Lost(e), _) if job.resubmissions < MAX_RESUBMISSIONS => {
+            say!(
+                "{}",
+                format!("⚠️ Transaction with nonce {} was not finalized ({}); resubmitting it with the same nonce.", nonce, e)
+                    .yellow()
+            );
+            job.accepted = true;
+            job.resubmissions += 1;
+            queue.push_front(job);
+            Ok(())
+        }
+        (Outcome::Lost(e), Some(index)) => {
+            // The transaction may still be in the pool, which the next index of the account counts,
+            // so the nonce is only reused once the node confirms it is free.
+            let checked = async { Ok::<_, Error>(get_legacy_rpc().await?.system_account_next_index(account).await?) };
+            let next_index = match checked.await {
+                Ok(next_index) => next_index,
+                Err(check) => {
+                    on_result(index, Err(e))?;
+                    return Err(Error::Other(format!(
+                        "Could not check whether nonce {} of {} is still in use ({}), so no more transactions are sent.",
+                        nonce, account, check
+                    )));
+                }
+            };
+            if next_index > nonce {
+                return on_result(
+                    index,
+                    Err(Error::Other(format!(
+                        "The transaction with nonce {} was not finalized ({}), and its nonce is still in use. \
+                         It is not signed again, since it may be included after all.",
+                        nonce, e
+                    ))),
+                );
+            }
+            nonces.free.insert(nonce);
+            on_result(index, Err(e))
+        }
+        // Without the remark every later transaction would wait in the pool forever.
+        (Outcome::Rejected(_), None) | (Outcome::Lost(_), None) => Err(Error::Other(format!(
+            "Nonce {} of {} could not be filled with a remark, so the transactions after it cannot be included.",
+            nonce, account
+        ))),
+    }
+}
+
+fn sign<Call: Payload>(api: &OnlineClient<PolkadotConfig>, payload: &Call, keypair: &Keypair, nonce: u64) -> Result<Extrinsic> {
+    let params = PolkadotExtrinsicParamsBuilder::<PolkadotConfig>::new().nonce(nonce).build();
+    Ok(api.tx().create_signed_offline(payload, keypair, params)?)
+}
+
+async fn watch(extrinsic: Extrinsic) -> Outcome {
+    let progress = match extrinsic.submit_and_watch().await {
+        Ok(progress) => progress,
+        Err(e) => return Outcome::Rejected(e),
+    };
+    match progress.wait_for_finalized().await {
+        Ok(in_block) => Outcome::Finalized(tx_utils::finalized_result(in_block).await),
+        Err(e) => Outcome::Lost(e.into()),
+    }
+}
+
+// Whether the node rejected a transaction because its nonce is used by another transaction.
+fn nonce_taken(error: &subxt::Error) -> bool {
+    let message = error.to_string();
+    message.contains("Priority is too low") || message.contains("Transaction is outdated")
+}
@@ -0,0 +1,27 @@
+use crate::error::{Error, Result};
+use crate::utils::tx_utils;
+use colored::*;
+use std::io::{self, IsTerminal, Write};
+
+/// Ask before an irreversible action, unless it was confirmed up front with `--yes`.
+///
+/// Nothing is asked with `--dry-run` or `tx build`, which broadcast nothing. Without a
+/// terminal to ask on the action is refused, so scripts have to opt in with `--yes`.
+pub fn confirm(question: &str, yes: bool) -> Result<()> {
+    if yes || tx_utils::is_dry_run() || tx_utils::is_build() {
+        return Ok(());
+    }
+    if !io::stdin().is_terminal() {
+        return Err(Error::invalid_input("Cannot ask for confirmation without a terminal. Pass --yes to proceed."));
+    }
+
+    // The prompt goes to stderr to keep stdout clean for `--output json`.
+    eprint!("{} {} ", question.yellow().bold(), "[y/N]".dimmed());
+    io::stderr().flush()?;
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+    match answer.trim().to_lowercase().as_str() {
+        "y" | "yes" => Ok(()),
+        _ => Err(Error::Other("Cancelled.".to_string())),
+    }
+}
@@ -0,0 +1,366 @@
+use crate::cli::MintSettingsArgs;
+use crate::commands::assethub;
+use crate::error::{Error, Result};
+use crate::network::TokenInfo;
+use clap::ValueEnum;
+use colored::*;
+use pallet_nfts::{CollectionRole, CollectionSetting, CollectionSettings, ItemSetting, ItemSettings};
+use serde_json::{json, Value};
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use assethub::runtime_types::pallet_nfts::types::{BitFlags1, BitFlags2, MintType};
+
+pub type CollectionConfig = assethub::runtime_types::pallet_nfts::types::CollectionConfig<u128, u32, u32>;
+pub type MintSettings = assethub::runtime_types::pallet_nfts::types::MintSettings<u128, u32, u32>;
+
+/// Who can mint the items of a collection (`--mint-type`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum MintTypeArg {
+    /// Only the collection's issuer
+    Issuer,
+    /// Anyone
+    Public,
+    /// Holders of an item of another collection, given with --holder-of
+    HolderOf,
+}
+
+/// A collection setting that can be locked when the collection is created (`--lock-collection`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CollectionLock {
+    /// Items cannot be transferred
+    Transfers,
+    /// The collection metadata cannot be changed
+    Metadata,
+    /// The collection attributes cannot be changed
+    Attributes,
+    /// The max supply cannot be changed
+    MaxSupply,
+}
+
+impl CollectionLock {
+    fn setting(self) -> CollectionSetting {
+        match self {
+            CollectionLock::Transfers => CollectionSetting::TransferableItems,
+            CollectionLock::Metadata => CollectionSetting::UnlockedMetadata,
+            CollectionLock::Attributes => CollectionSetting::UnlockedAttributes,
+            CollectionLock::MaxSupply => CollectionSetting::UnlockedMaxSupply,
+        }
+    }
+}
+
+/// An optional value given on the command line, where `none` clears it.
+#[derive(Clone, Copy, Debug)]
+pub struct OrNone<T>(pub Option<T>);
+
+impl<T: FromStr> FromStr for OrNone<T>
+where
+    T::Err: Display,
+{
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("none") {
+            return Ok(OrNone(None));
+        }
+        s.parse().map(|value| OrNone(Some(value))).map_err(|e: T::Err| e.to_string())
+    }
+}
+
+/// The configuration of a new collection: its max supply, locked settings and mint settings.
+pub fn collection_config(
+    max_supply: Option<u32>,
+    locks: &[CollectionLock],
+    mint: &MintSettingsArgs,
+    token: &TokenInfo,
+) -> Result<CollectionConfig> {
+    Ok(CollectionConfig {
+        settings: collection_settings(CollectionSettings::all_enabled().0.bits(), locks, &[]),
+        max_supply,
+        mint_settings: mint_settings(mint, None, token)?,
+        __ignore: Default::default(),
+    })
+}
+
+/// Collection settings with the `lock` settings locked and the `unlock` settings unlocked.
+///
+/// The pallet stores the settings that are disabled, so locking one sets its bit.
+pub fn collection_settings(current: u64, lock: &[CollectionLock], unlock: &[CollectionLock]) -> BitFlags1<assethub::runtime_types::pallet_nfts::types::CollectionSetting> {
+    let mut bits = current;
+    for setting in lock {
+        bits |= setting.setting() as u64;
+    }
+    for setting in unlock {
+        bits &= !(setting.setting() as u64);
+    }
+    BitFlags1(bits, PhantomData)
+}
+
+/// Mint settings from the command line options, keeping the `current` value of the options left out.
+///
+/// A new collection (`current` is `None`) starts from issuer-only minting without a price or
+/// mint window, and with every item setting unlocked.
+pub fn mint_settings(args: &MintSettingsArgs, current: Option<MintSettings>, token: &TokenInfo) -> Result<MintSettings> {
+    let mut settings = current.unwrap_or(MintSettings {
+        mint_type: MintType::Issuer,
+        price: None,
+        start_block: None,
+        end_block: None,
+        default_item_settings: BitFlags2(ItemSettings::all_enabled().0.bits(), PhantomData),
+        __ignore: Default::default(),
+    });
+
+    match (args.mint_type, args.holder_of) {
+        (Some(MintTypeArg::Issuer), None) => settings.mint_type = MintType::Issuer,
+        (Some(MintTypeArg::Public), None) => settings.mint_type = MintType::Public,
+        (Some(MintTypeArg::HolderOf), Some(collection)) => settings.mint_type = MintType::HolderOf(collection),
+        (None, None) => {}
+        _ => return Err(Error::invalid_input("--holder-of is only used with --mint-type holder-of.")),
+    }
+    if let Some(OrNone(price)) = &args.mint_price {
+        settings.price = price.as_ref().map(|price| token.to_plancks(price)).transpose()?;
+    }
+    if let Some(OrNone(start_block)) = args.mint_start {
+        settings.start_block = start_block;
+    }
+    if let Some(OrNone(end_block)) = args.mint_end {
+        settings.end_block = end_block;
+    }
+    if let (Some(start), Some(end)) = (settings.start_block, settings.end_block) {
+        if start > end {
+            return Err(Error::invalid_input(format!("The mint window ends at block {} before it starts at block {}.", end, start)));
+        }
+    }
+
+    // Item settings are stored as the disabled ones too, so locking one sets its bit.
+    let item_locks = [
+        (args.lock_item_transfers, ItemSetting::Transferable),
+        (args.lock_item_metadata, ItemSetting::UnlockedMetadata),
+        (args.lock_item_attributes, ItemSetting::UnlockedAttributes),
+    ];
+    for (lock, setting) in item_locks {
+        match lock {
+            Some(true) => settings.default_item_settings.0 |= setting as u64,
+            Some(false) => settings.default_item_settings.0 &= !(setting as u64),
+            None => {}
+        }
+    }
+    Ok(settings)
+}
+
+/// Print mint settings in the style of the other command reports.
+pub fn print_mint_settings(settings: &MintSettings, token: &TokenInfo) {
+    let mint_type = match settings.mint_type {
+        MintType::Issuer => "Issuer only".to_string(),
+        MintType::Public => "Public".to_string(),
+        MintType::HolderOf(collection) => format!("Holders of collection {}", collection),
+    };
+    let block = |block: Option<u32>| block.map_or("-".to_string(), |block| format!("#{}", block));
+    let locked = item_locks(settings.default_item_settings.0);
+
+    say!("{}: {}", "🪙 Mint Type".cyan().bold(), mint_type.bright_white());
+    say!(
+        "{}: {}",
+        "💰 Mint Price".cyan().bold(),
+        settings.price.map_or("Free".to_string(), |price| token.format_plancks(price)).bright_white()
+    );
+    say!(
+        "{}: {} to {}",
+        "⏳ Mint Window".cyan().bold(),
+        block(settings.start_block).bright_white(),
+        block(settings.end_block).bright_white()
+    );
+    say!(
+        "{}: {}",
+        "🔒 Locked Item Settings".cyan().bold(),
+        if locked.is_empty() { "None".to_string() } else { locked.join(", ") }.bright_white()
+    );
+}
+
+pub fn mint_settings_to_json(settings: &MintSettings) -> Value {
+    let (mint_type, holder_of) = match settings.mint_type {
+        MintType::Issuer => ("issuer", None),
+        MintType::Public => ("public", None),
+        MintType::HolderOf(collection) => ("holder-of", Some(collection)),
+    };
+    json!({
+        "mint_type": mint_type,
+        "holder_of": holder_of,
+        "price": settings.price.map(|price| price.to_string()),
+        "start_block": settings.start_block,
+        "end_block": settings.end_block,
+        "locked_item_settings": item_locks(settings.default_item_settings.0),
+    })
+}
+
+/// What item settings lock: the pallet stores the disabled settings, so a set bit is a lock.
+pub fn item_locks(bits: u64) -> Vec<&'static str> {
+    [
+        (ItemSetting::Transferable, "transfer"),
+        (ItemSetting::UnlockedMetadata, "metadata"),
+        (ItemSetting::UnlockedAttributes, "attributes"),
+    ]
+    .into_iter()
+    .filter(|(setting, _)| bits & *setting as u64 != 0)
+    .map(|(_, name)| name)
+    .collect()
+}
+
+/// What collection settings lock, named like the `--lock-collection` values; a set bit is a lock.
+pub fn collection_locks(bits: u64) -> Vec<&'static str> {
+    [
+        (CollectionSetting::TransferableItems, "transfers"),
+        (CollectionSetting::UnlockedMetadata, "metadata"),
+        (CollectionSetting::UnlockedAttributes, "attributes"),
+        (CollectionSetting::UnlockedMaxSupply, "max-supply"),
+    ]
+    .into_iter()
+    .filter(|(setting, _)| bits & *setting as u64 != 0)
+    .map(|(_, name)| name)
+    .collect()
+}
+
+/// Print a collection's configuration: its max supply, locked settings and mint settings.
+pub fn print_collection_config(config: &CollectionConfig, token: &TokenInfo) {
+    let locked = collection_locks(config.settings.0);
+    say!(
+        "{}: {}",
+        "📈 Max Supply".cyan().bold(),
+        config.max_supply.map_or("Unlimited".to_string(), |max_supply| max_supply.to_string()).bright_white()
+    );
+    say!(
+        "{}: {}",
+        "🔒 Locked Collection Settings".cyan().bold(),
+        if locked.is_empty() { "None".to_string() } else { locked.join(", ") }.bright_white()
+    );
+    say!(
+        "{}: {}",
+        "💎 Deposits Required".cyan().bold(),
+        if deposit_required(config) { "Yes" } else { "No" }.bright_white()
+    );
+    print_mint_settings(&config.mint_settings, token);
+}
+
+pub fn collection_config_to_json(config: &CollectionConfig) -> Value {
+    json!({
+        "max_supply": config.max_supply,
+        "locked_settings": collection_locks(config.settings.0),
+        "deposit_required": deposit_required(config),
+        "mint_settings": mint_settings_to_json(&config.mint_settings),
+    })
+}
+
+// `DepositRequired` is disabled like the other settings, so a set bit waives the deposits.
+fn deposit_required(config: &CollectionConfig) -> bool {
+    config.settings.0 & CollectionSetting::DepositRequired as u64 == 0
+}
+
+/// The collection roles held according to `CollectionRoleOf` bits.
+pub fn role_names(bits: u8) -> Vec<&'static str> {
+    [
+        (CollectionRole::Issuer, "Issuer"),
+        (CollectionRole::Admin, "Admin"),
+        (CollectionRole::Freezer, "Freezer"),
+    ]
+    .into_iter()
+    .filter(|(role, _)| bits & *role as u8 != 0)
+    .map(|(_, name)| name)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use subxt::ext::codec::{Decode, Encode};
+
+    // Decode bits as the pallet does, so that their meaning is the pallet's.
+    fn decode<T: Decode>(bits: u64) -> T {
+        T::decode(&mut bits.encode().as_slice()).unwrap()
+    }
+
+    // The bits of settings as the pallet encodes them.
+    trait EncodeAsBits: Encode {
+        fn encode_as_bits(&self) -> u64 {
+            u64::decode(&mut self.encode().as_slice()).unwrap()
+        }
+    }
+
+    impl EncodeAsBits for ItemSettings {}
+    impl EncodeAsBits for CollectionSettings {}
+
+    fn token() -> TokenInfo {
+        TokenInfo { symbol: "UNIT".to_string(), decimals: 12 }
+    }
+
+    fn mint_args() -> MintSettingsArgs {
+        MintSettingsArgs {
+            mint_type: None,
+            holder_of: None,
+            mint_price: None,
+            mint_start: None,
+            mint_end: None,
+            lock_item_transfers: None,
+            lock_item_metadata: None,
+            lock_item_attributes: None,
+        }
+    }
+
+    #[test]
+    fn locks_collection_settings() {
+        let bits = collection_settings(CollectionSettings::all_enabled().0.bits(), &[CollectionLock::Transfers, CollectionLock::MaxSupply], &[]).0;
+        let settings: CollectionSettings = decode(bits);
+        assert!(settings.is_disabled(CollectionSetting::TransferableItems));
+        assert!(settings.is_disabled(CollectionSetting::UnlockedMaxSupply));
+        assert!(!settings.is_disabled(CollectionSetting::UnlockedMetadata));
+        assert!(!settings.is_disabled(CollectionSetting::UnlockedAttributes));
+        assert!(!settings.is_disabled(CollectionSetting::DepositRequired));
+
+        let settings: CollectionSettings = decode(collection_settings(bits, &[], &[CollectionLock::Transfers]).0);
+        assert!(!settings.is_disabled(CollectionSetting::TransferableItems));
+        assert!(settings.is_disabled(CollectionSetting::UnlockedMaxSupply));
+    }
+
+    #[test]
+    fn reads_item_locks() {
+        assert!(item_locks(ItemSettings::all_enabled().encode_as_bits()).is_empty());
+        let frozen = ItemSettings::from_disabled(ItemSetting::Transferable | ItemSetting::UnlockedAttributes);
+        assert_eq!(item_locks(frozen.encode_as_bits()), ["transfer", "attributes"]);
+    }
+
+    #[test]
+    fn reads_collection_locks() {
+        assert!(collection_locks(CollectionSettings::all_enabled().encode_as_bits()).is_empty());
+        let locked = CollectionSettings::from_disabled(CollectionSetting::UnlockedMetadata | CollectionSetting::DepositRequired);
+        assert_eq!(collection_locks(locked.encode_as_bits()), ["metadata"]);
+    }
+
+    #[test]
+    fn locking_then_unlocking_keeps_other_settings() {
+        let original = CollectionSettings::from_disabled(CollectionSetting::UnlockedMetadata | CollectionSetting::DepositRequired);
+        let bits = original.encode_as_bits();
+        let locks = [CollectionLock::Transfers, CollectionLock::MaxSupply];
+        let locked = collection_settings(bits, &locks, &[]).0;
+        assert_eq!(collection_locks(locked), ["transfers", "metadata", "max-supply"]);
+        assert_eq!(collection_settings(locked, &[], &locks).0, bits);
+    }
+
+    #[test]
+    fn locks_default_item_settings() {
+        let args = MintSettingsArgs { lock_item_transfers: Some(true), lock_item_attributes: Some(true), ..mint_args() };
+        let settings = mint_settings(&args, None, &token()).unwrap();
+        let item_settings: ItemSettings = decode(settings.default_item_settings.0);
+        assert!(item_settings.is_disabled(ItemSetting::Transferable));
+        assert!(item_settings.is_disabled(ItemSetting::UnlockedAttributes));
+        assert!(!item_settings.is_disabled(ItemSetting::UnlockedMetadata));
+
+        let args = MintSettingsArgs { lock_item_transfers: Some(false), ..mint_args() };
+        let settings = mint_settings(&args, Some(settings), &token()).unwrap();
+        let item_settings: ItemSettings = decode(settings.default_item_settings.0);
+        assert!(!item_settings.is_disabled(ItemSetting::Transferable));
+        assert!(item_settings.is_disabled(ItemSetting::UnlockedAttributes));
+
+        let settings = mint_settings(&mint_args(), None, &token()).unwrap();
+        assert_eq!(decode::<ItemSettings>(settings.default_item_settings.0), ItemSettings::all_enabled());
+    }
+}
@@ -0,0 +1,610 @@
+use crate::client::{get_client, get_legacy_rpc, get_token_info};
+use crate::commands::assethub;
+use crate::error::{Error, Result};
+use crate::signer::AccountSigner;
+use colored::*;
+use once_cell::sync::OnceCell;
+use scale_info::{PortableRegistry, TypeDef, TypeDefPrimitive};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use subxt::backend::legacy::rpc_methods::DryRunResult;
+use subxt::blocks::ExtrinsicEvents;
+use subxt::client::{OfflineClient, OfflineClientT, RuntimeVersion};
+use subxt::config::polkadot::PolkadotExtrinsicParamsBuilder;
+use subxt::error::DispatchError;
+use subxt::ext::codec::{Compact, Decode, Encode};
+use subxt::ext::scale_value::{self, Composite, Primitive, ValueDef};
+use subxt::tx::{PartialExtrinsic, Payload, SubmittableExtrinsic, TxInBlock, TxProgress};
+use subxt::utils::{AccountId32, H256};
+use subxt::{Metadata, OnlineClient, PolkadotConfig};
+
+static DRY_RUN: OnceCell<()> = OnceCell::new();
+static DRY_RUN_REPORTS: Mutex<Vec<Value>> = Mutex::new(Vec::new());
+
+/// Simulate extrinsics instead of broadcasting them (`--dry-run`).
+pub fn enable_dry_run() {
+    DRY_RUN.set(()).ok();
+}
+
+pub fn is_dry_run() -> bool {
+    DRY_RUN.get().is_some()
+}
+
+/// Reports of the extrinsics simulated so far, for `--output json`.
+pub fn dry_run_reports() -> Vec<Value> {
+    DRY_RUN_REPORTS.lock().expect("dry run reports lock").clone()
+}
+
+static BUILD: OnceCell<BuildOptions> = OnceCell::new();
+static BUILT: OnceCell<UnsignedTx> = OnceCell::new();
+
+// The metadata the `assethub` module was generated from, to sign transactions without a connection.
+static METADATA: &[u8] = include_bytes!("../../metadata.scale");
+
+/// Options of `tx build`.
+pub struct BuildOptions {
+    /// Number of blocks the transaction stays valid for; `0` makes it immortal.
+    pub mortality: u64,
+}
+
+/// Build unsigned transactions instead of signing them (`tx build`).
+pub fn enable_build(options: BuildOptions) {
+    BUILD.set(options).ok();
+}
+
+pub fn is_build() -> bool {
+    BUILD.get().is_some()
+}
+
+/// The transaction built by the command run under `tx build`, if it submits one.
+pub fn built() -> Option<&'static UnsignedTx> {
+    BUILT.get()
+}
+
+/// A transaction built by `tx build`, with everything `tx sign` needs to sign it offline.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct UnsignedTx {
+    /// Name of the call, e.g. `Balances.transfer_keep_alive`.
+    pub call: String,
+    /// SCALE-encoded call data, hex encoded.
+    pub call_data: String,
+    /// SS58 address of the account that has to sign the transaction.
+    pub signer: String,
+    pub nonce: u64,
+    /// Validity period of the transaction; `None` if it is immortal.
+    pub mortality: Option<Mortality>,
+    pub genesis_hash: String,
+    pub spec_version: u32,
+    pub transaction_version: u32,
+    /// The bytes that get signed, hex encoded; `tx sign` checks them against the fields above.
+    pub signer_payload: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Mortality {
+    /// Number of the block the validity period starts at.
+    pub block_number: u64,
+    pub block_hash: String,
+    /// Number of blocks the transaction stays valid for.
+    pub period: u64,
+}
+
+impl UnsignedTx {
+    async fn build<Call: Payload>(payload: &Call, signer: &AccountId32, period: u64) -> Result<Self> {
+        let api = get_client().await?;
+        let call_data = api.tx().call_data(payload)?;
+        let (call, _) = decode_call(&call_data, &api.metadata())?;
+        let nonce = api.tx().account_nonce(signer).await?;
+        let mortality = match period {
+            0 => None,
+            period => {
+                let block = api.blocks().at_latest().await?;
+                Some(Mortality {
+                    block_number: block.number().into(),
+                    block_hash: format!("{:?}", block.hash()),
+                    period,
+                })
+            }
+        };
+        let runtime_version = api.runtime_version();
+
+        let mut unsigned = UnsignedTx {
+            call,
+            call_data: format!("0x{}", hex::encode(call_data)),
+            signer: signer.to_string(),
+            nonce,
+            mortality,
+            genesis_hash: format!("{:?}", api.genesis_hash()),
+            spec_version: runtime_version.spec_version,
+            transaction_version: runtime_version.transaction_version,
+            signer_payload: String::new(),
+        };
+        unsigned.signer_payload = format!("0x{}", hex::encode(unsigned.partial(api)?.signer_payload()));
+        Ok(unsigned)
+    }
+
+    /// A client for the chain the transaction was built for, using the bundled metadata.
+    pub fn offline_client(&self) -> Result<OfflineClient<PolkadotConfig>> {
+        let metadata = Metadata::decode(&mut &METADATA[..])
+            .map_err(|e| Error::Other(format!("Invalid bundled metadata: {}", e)))?;
+        let runtime_version = RuntimeVersion {
+            spec_version: self.spec_version,
+            transaction_version: self.transaction_version,
+        };
+        Ok(OfflineClient::new(parse_hash(&self.genesis_hash)?, runtime_version, metadata))
+    }
+
+    /// The transaction with its signed extensions, ready to be signed.
+    pub fn partial<C: OfflineClientT<PolkadotConfig>>(&self, client: &C) -> Result<PartialExtrinsic<PolkadotConfig, C>> {
+        let mut params = PolkadotExtrinsicParamsBuilder::<PolkadotConfig>::new().nonce(self.nonce);
+        if let Some(mortality) = &self.mortality {
+            params = params.mortal_unchecked(mortality.block_number, parse_hash(&mortality.block_hash)?, mortality.period);
+        }
+        let call = RawCall(decode_hex(&self.call_data)?);
+        Ok(client.tx().create_partial_signed_offline(&call, params.build())?)
+    }
+}
+
+// Call data that is already encoded, such as the call data of an `UnsignedTx`.
+struct RawCall(Vec<u8>);
+
+impl Payload for RawCall {
+    fn encode_call_data_to(
+        &self,
+        _metadata: &Metadata,
+        out: &mut Vec<u8>,
+    ) -> std::result::Result<(), subxt::ext::subxt_core::Error> {
+        out.extend_from_slice(&self.0);
+        Ok(())
+    }
+}
+
+/// Decode call data into the call name, e.g. `Balances.transfer_keep_alive`, and its arguments.
+pub fn decode_call(call_data: &[u8], metadata: &Metadata) -> Result<(String, Value)> {
+    let invalid = |message: String| Error::invalid_input(format!("Invalid call data: {}", message));
+    let mut input = call_data;
+    let value = scale_value::scale::decode_as_type(&mut input, metadata.outer_enums().call_enum_ty(), metadata.types())
+        .map_err(|e| invalid(e.to_string()))?;
+    if !input.is_empty() {
+        return Err(invalid(format!("{} trailing bytes", input.len())));
+    }
+    match value.value {
+        ValueDef::Variant(pallet) => match pallet.values.into_values().next().map(|call| call.value) {
+            Some(ValueDef::Variant(call)) => Ok((
+                format!("{}.{}", pallet.name, call.name),
+                composite_to_json(&call.values, metadata.types()),
+            )),
+            _ => Err(invalid(format!("no call of pallet {}", pallet.name))),
+        },
+        _ => Err(invalid("not a runtime call".to_string())),
+    }
+}
+
+/// Decode a hex string, with or without `0x` prefix.
+pub fn decode_hex(hex_str: &str) -> Result<Vec<u8>> {
+    let hex_str = hex_str.trim();
+    hex::decode(hex_str.strip_prefix("0x").unwrap_or(hex_str))
+        .map_err(|e| Error::invalid_input(format!("Invalid hex '{}': {}", hex_str, e)))
+}
+
+fn parse_hash(hash: &str) -> Result<H256> {
+    let bytes = decode_hex(hash)?;
+    <[u8; 32]>::try_from(bytes.as_slice())
+        .map(H256)
+        .map_err(|_| Error::invalid_input(format!("Invalid block hash '{}'", hash)))
+}
+
+/// The outcome of a finalized, successful extrinsic.
+pub struct TxResult {
+    pub block_hash: H256,
+    pub events: ExtrinsicEvents<PolkadotConfig>,
+    /// Fee in plancks paid for the extrinsic, if reported by the runtime.
+    pub fee: Option<u128>,
+}
+
+impl TxResult {
+    pub fn extrinsic_hash(&self) -> H256 {
+        self.events.extrinsic_hash()
+    }
+
+    /// Extrinsic hash, block hash and decoded events, for `--output json`.
+    pub fn to_json(&self, metadata: &Metadata) -> Result<Value> {
+        Ok(json!({
+            "extrinsic_hash": format!("{:?}", self.extrinsic_hash()),
+            "block_hash": format!("{:?}", self.block_hash),
+            "fee": self.fee.map(|fee| fee.to_string()),
+            "events": events_to_json(&self.events, metadata)?,
+        }))
+    }
+}
+
+/// Sign and submit an extrinsic, then wait until it is finalized.
+///
+/// With `--dry-run` the extrinsic is signed and simulated instead, and under `tx build` it is
+/// only built for signing elsewhere. `None` is returned then; callers stop there, since nothing
+/// was written on chain.
+pub async fn submit<Call: Payload>(payload: &Call, signer: &AccountSigner) -> Result<Option<TxResult>> {
+    if let Some(options) = BUILD.get() {
+        let unsigned = UnsignedTx::build(payload, &signer.account_id(), options.mortality).await?;
+        BUILT.set(unsigned).ok();
+        return Ok(None);
+    }
+
+    let api = get_client().await?;
+    let signer = signer.keypair()?;
+    if is_dry_run() {
+        let extrinsic = api.tx().create_signed(payload, signer, Default::default()).await?;
+        let name = payload
+            .validation_details()
+            .map(|details| format!("{}.{}", details.pallet_name, details.call_name))
+            .unwrap_or_else(|| "extrinsic".to_string());
+        dry_run(&name, &extrinsic).await?;
+        return Ok(None);
+    }
+
+    let progress = api.tx().sign_and_submit_then_watch_default(payload, signer).await?;
+    wait_for_finalized_success(progress).await.map(Some)
+}
+
+/// The runtime's call type, as packed into `Utility::batch_all`.
+pub type RuntimeCall = assethub::runtime_types::asset_hub_paseo_runtime::RuntimeCall;
+type Weight = assethub::runtime_types::sp_weights::weight_v2::Weight;
+
+/// Calls packed into a single `Utility::batch_all` extrinsic, up to the limits of one extrinsic:
+/// the weight of a normal extrinsic, the block length and the number of batched calls.
+///
+/// The weight and length of the batch are kept as running sums, so that packing n calls takes
+/// one weight query per kind of call rather than one per call. This relies on the weight of the
+/// calls batched here (mints, burns, metadata and attributes) depending on the call alone, not
+/// on its arguments, which holds for the `Nfts` pallet.
+pub struct Batch {
+    max_weight: Weight,
+    max_length: u32,
+    max_calls: u32,
+    // Weight of an empty `batch_all`, and what each kind of call adds to it.
+    empty_weight: Weight,
+    call_weights: HashMap<[u8; 2], Weight>,
+    // Length of a signed extrinsic around its call data, without its length prefix.
+    extrinsic_overhead: u32,
+    // Length of an empty `batch_all` call, without the number of calls.
+    batch_overhead: u32,
+    calls: Vec<RuntimeCall>,
+    weight: Weight,
+    calls_length: u32,
+}
+
+impl Batch {
+    pub async fn new() -> Result<Self> {
+        let api = get_client().await?;
+        let constants = api.constants();
+        let block_weights = constants.at(&assethub::constants().system().block_weights())?;
+        let block_length = constants.at(&assethub::constants().system().block_length())?;
+        let empty_batch = batch_all(Vec::new());
+        Ok(Batch {
+            max_weight: block_weights.per_class.normal.max_extrinsic.unwrap_or(block_weights.max_block),
+            max_length: block_length.max.normal,
+            max_calls: constants.at(&assethub::constants().utility().batched_calls_limit())?,
+            empty_weight: call_weight(empty_batch.clone()).await?,
+            call_weights: HashMap::new(),
+            extrinsic_overhead: extrinsic_overhead(api)?,
+            batch_overhead: (empty_batch.encoded_size() - Compact(0u32).encoded_size()) as u32,
+            calls: Vec::new(),
+            weight: Weight { ref_time: 0, proof_size: 0 },
+            calls_length: 0,
+        })
+    }
+
+    /// Add calls that have to end up in the same extrinsic.
+    ///
+    /// Returns false, leaving the batch unchanged, if they would push it over a limit.
+    /// An empty batch always takes the calls.
+    pub async fn try_push(&mut self, calls: Vec<RuntimeCall>) -> Result<bool> {
+        let count = self.calls.len() + calls.len();
+        let mut weight = self.weight.clone();
+        let mut calls_length = self.calls_length;
+        for call in &calls {
+            let added = self.added_weight(call).await?;
+            weight = Weight {
+                ref_time: weight.ref_time.saturating_add(added.ref_time),
+                proof_size: weight.proof_size.saturating_add(added.proof_size),
+            };
+            calls_length += call.encoded_size() as u32;
+        }
+
+        if !self.calls.is_empty() {
+            let call_length = self.batch_overhead + Compact(count as u32).encoded_size() as u32 + calls_length;
+            let inner_length = self.extrinsic_overhead + call_length;
+            let length = Compact(inner_length).encoded_size() as u32 + inner_length;
+            if count > self.max_calls as usize
+                || self.empty_weight.ref_time.saturating_add(weight.ref_time) > self.max_weight.ref_time
+                || self.empty_weight.proof_size.saturating_add(weight.proof_size) > self.max_weight.proof_size
+                || length > self.max_length
+            {
+                return Ok(false);
+            }
+        }
+
+        self.calls.extend(calls);
+        self.weight = weight;
+        self.calls_length = calls_length;
+        Ok(true)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.calls.is_empty()
+    }
+
+    /// The `batch_all` extrinsic of the calls, leaving the batch empty.
+    pub fn take(&mut self) -> subxt::tx::DefaultPayload<assethub::utility::calls::types::BatchAll> {
+        self.weight = Weight { ref_time: 0, proof_size: 0 };
+        self.calls_length = 0;
+        assethub::tx().utility().batch_all(std::mem::take(&mut self.calls))
+    }
+
+    // What a call adds to the weight of a `batch_all`, queried once per pallet and call index.
+    async fn added_weight(&mut self, call: &RuntimeCall) -> Result<Weight> {
+        let encoded = call.encode();
+        let kind = [encoded[0], encoded.get(1).copied().unwrap_or_default()];
+        if let Some(weight) = self.call_weights.get(&kind) {
+            return Ok(weight.clone());
+        }
+        let batched = call_weight(batch_all(vec![call.clone()])).await?;
+        let weight = Weight {
+            ref_time: batched.ref_time.saturating_sub(self.empty_weight.ref_time),
+            proof_size: batched.proof_size.saturating_sub(self.empty_weight.proof_size),
+        };
+        self.call_weights.insert(kind, weight.clone());
+        Ok(weight)
+    }
+}
+
+fn batch_all(calls: Vec<RuntimeCall>) -> RuntimeCall {
+    RuntimeCall::Utility(assethub::runtime_types::pallet_utility::pallet::Call::batch_all { calls })
+}
+
+// The weight the runtime gives a call.
+async fn call_weight(call: RuntimeCall) -> Result<Weight> {
+    let length = call.encoded_size() as u32;
+    let query = assethub::apis().transaction_payment_call_api().query_call_info(call, length);
+    Ok(get_client().await?.runtime_api().at_latest().await?.call(query).await?.weight)
+}
+
+// How much a signed extrinsic adds to its call data, measured on a real encoding with the
+// largest nonce, tip and era, so that it holds for any extrinsic of the account.
+fn extrinsic_overhead(api: &OnlineClient<PolkadotConfig>) -> Result<u32> {
+    let remark = assethub::tx().system().remark(Vec::new());
+    let params = PolkadotExtrinsicParamsBuilder::<PolkadotConfig>::new()
+        .nonce(u64::MAX)
+        .tip(u128::MAX)
+        .mortal_unchecked(u64::MAX, api.genesis_hash(), u16::MAX.into())
+        .build();
+    let extrinsic = api.tx().create_partial_signed_offline(&remark, params)?.sign_with_address_and_signature(
+        &AccountId32([0; 32]).into(),
+        &subxt::utils::MultiSignature::Sr25519([0; 64]),
+    );
+    let encoded = extrinsic.encoded();
+    let prefix = Compact::<u32>::decode(&mut &encoded[..]).map_err(subxt::Error::from)?;
+    Ok(prefix.0 - api.tx().call_data(&remark)?.len() as u32)
+}
+
+enum Simulation {
+    Success,
+    Failure(String),
+    Unavailable(String),
+}
+
+/// Estimate the fees of a signed extrinsic and simulate it with `system_dryRun`.
+pub async fn dry_run(
+    name: &str,
+    extrinsic: &SubmittableExtrinsic<PolkadotConfig, OnlineClient<PolkadotConfig>>,
+) -> Result<()> {
+    let api = get_client().await?;
+    let token = get_token_info().await?;
+
+    let mut params = extrinsic.encoded().to_vec();
+    (extrinsic.encoded().len() as u32).encode_to(&mut params);
+    let runtime_api = api.runtime_api().at_latest().await?;
+    let info: assethub::runtime_types::pallet_transaction_payment::types::RuntimeDispatchInfo<
+        u128,
+        assethub::runtime_types::sp_weights::weight_v2::Weight,
+    > = runtime_api.call_raw("TransactionPaymentApi_query_info", Some(&params)).await?;
+    let details: assethub::runtime_types::pallet_transaction_payment::types::FeeDetails<u128> =
+        runtime_api.call_raw("TransactionPaymentApi_query_fee_details", Some(&params)).await?;
+
+    // Public nodes often disable the unsafe `system_dryRun`; the fee estimate is still useful then.
+    let simulation = match get_legacy_rpc().await?.dry_run(extrinsic.encoded(), None).await {
+        Ok(bytes) => match bytes.into_dry_run_result(&api.metadata())? {
+            DryRunResult::Success => Simulation::Success,
+            DryRunResult::DispatchError(e) => Simulation::Failure(Error::from(e).to_string()),
+            DryRunResult::TransactionValidityError => Simulation::Failure(
+                "The transaction is invalid (e.g. bad nonce or too little balance for fees).".to_string(),
+            ),
+        },
+        Err(e) => Simulation::Unavailable(e.to_string()),
+    };
+
+    let inclusion_fee = details.inclusion_fee.as_ref();
+    say!("\n{} {}\n", "🧪 Dry run:".blue().bold(), name.bright_white());
+    say!("{}: {}", "💰 Estimated fee".cyan().bold(), token.format_plancks(info.partial_fee).bright_white());
+    if let Some(fee) = inclusion_fee {
+        say!("   {}: {}", "Base fee".cyan(), token.format_plancks(fee.base_fee));
+        say!("   {}: {}", "Length fee".cyan(), token.format_plancks(fee.len_fee));
+        say!("   {}: {}", "Weight fee".cyan(), token.format_plancks(fee.adjusted_weight_fee));
+    }
+    say!(
+        "{}: ref_time {}, proof_size {}",
+        "⚖️ Weight".cyan().bold(),
+        info.weight.ref_time,
+        info.weight.proof_size
+    );
+    say!("{}: {} bytes", "📏 Length".cyan().bold(), extrinsic.encoded().len());
+    match &simulation {
+        Simulation::Success => say!("{}", "✅ Would succeed.".green().bold()),
+        Simulation::Failure(message) => say!("{}", format!("❌ Would fail: {}", message).red().bold()),
+        Simulation::Unavailable(message) => say!("{}", format!("⚠️ Simulation unavailable: {}", message).yellow().bold()),
+    }
+    say!("{}", "ℹ️ Nothing was broadcast. Emitted events cannot be predicted without the runtime DryRunApi.".dimmed());
+
+    DRY_RUN_REPORTS.lock().expect("dry run reports lock").push(json!({
+        "call": name,
+        "length": extrinsic.encoded().len(),
+        "partial_fee": info.partial_fee.to_string(),
+        "fee_details": {
+            "base_fee": inclusion_fee.map(|fee| fee.base_fee.to_string()),
+            "len_fee": inclusion_fee.map(|fee| fee.len_fee.to_string()),
+            "adjusted_weight_fee": inclusion_fee.map(|fee| fee.adjusted_weight_fee.to_string()),
+            "tip": details.tip.to_string(),
+        },
+        "weight": { "ref_time": info.weight.ref_time, "proof_size": info.weight.proof_size },
+        "success": match &simulation {
+            Simulation::Success => json!(true),
+            Simulation::Failure(_) => json!(false),
+            Simulation::Unavailable(_) => Value::Null,
+        },
+        "error": match simulation {
+            Simulation::Success => None,
+            Simulation::Failure(message) | Simulation::Unavailable(message) => Some(message),
+        },
+        "events": null,
+    }));
+    Ok(())
+}
+
+/// Wait until the extrinsic is finalized, failing if it was not dispatched successfully.
+///
+/// A failed extrinsic is reported as [`Error::Dispatch`] with the pallet error decoded
+/// from the runtime metadata and the fee that was charged regardless.
+pub async fn wait_for_finalized_success(
+    progress: TxProgress<PolkadotConfig, OnlineClient<PolkadotConfig>>,
+) -> Result<TxResult> {
+    finalized_result(progress.wait_for_finalized().await?).await
+}
+
+/// The outcome of an extrinsic included in a finalized block, failing if it was not dispatched successfully.
+pub async fn finalized_result(in_block: TxInBlock<PolkadotConfig, OnlineClient<PolkadotConfig>>) -> Result<TxResult> {
+    let block_hash = in_block.block_hash();
+    let events = in_block.fetch_events().await?;
+    let fee = fee_paid(&events)?;
+
+    for event in events.iter() {
+        let event = event?;
+        if event.pallet_name() == "System" && event.variant_name() == "ExtrinsicFailed" {
+            let metadata = crate::client::get_client().await?.metadata();
+            let error = match Error::from(DispatchError::decode_from(event.field_bytes(), metadata)?) {
+                Error::Dispatch { pallet, error, docs, .. } => Error::Dispatch { pallet, error, docs, fee },
+                other => other,
+            };
+            if let (Some(fee), false) = (fee, crate::output::is_json()) {
+                let token = get_token_info().await?;
+                eprintln!(
+                    "{}: {}",
+                    "💸 Fee charged for the failed extrinsic".yellow().bold(),
+                    token.format_plancks(fee)
+                );
+            }
+            return Err(error);
+        }
+    }
+
+    Ok(TxResult { block_hash, events, fee })
+}
+
+// Sum of the fees paid by the extrinsic's signer, in plancks.
+fn fee_paid(events: &ExtrinsicEvents<PolkadotConfig>) -> Result<Option<u128>> {
+    let mut fee = None;
+    for paid in events.find::<assethub::transaction_payment::events::TransactionFeePaid>() {
+        let paid = paid?;
+        *fee.get_or_insert(0) += paid.actual_fee;
+    }
+    Ok(fee)
+}
+
+fn events_to_json(events: &ExtrinsicEvents<PolkadotConfig>, metadata: &Metadata) -> Result<Value> {
+    let mut decoded = Vec::new();
+    for event in events.iter() {
+        let event = event?;
+        decoded.push(json!({
+            "pallet": event.pallet_name(),
+            "event": event.variant_name(),
+            "fields": composite_to_json(&event.field_values()?, metadata.types()),
+        }));
+    }
+    Ok(Value::Array(decoded))
+}
+
+// Convert a decoded value to JSON. 64 and 128 bit integers (balances, weights) become
+// strings so that no precision is lost, and 32 byte arrays are shown as SS58 addresses.
+fn value_to_json(value: &scale_value::Value<u32>, types: &PortableRegistry) -> Value {
+    match &value.value {
+        ValueDef::Composite(composite) => composite_to_json(composite, types),
+        ValueDef::Variant(variant) => match &variant.values {
+            Composite::Unnamed(values) if values.is_empty() => Value::String(variant.name.clone()),
+            values => json!({ &variant.name: composite_to_json(values, types) }),
+        },
+        ValueDef::Primitive(primitive) => match primitive {
+            Primitive::Bool(b) => Value::Bool(*b),
+            Primitive::Char(c) => Value::String(c.to_string()),
+            Primitive::String(s) => Value::String(s.clone()),
+            Primitive::U128(n) if is_wide_integer(value.context, types) => Value::String(n.to_string()),
+            Primitive::U128(n) => json!(*n as u64),
+            Primitive::I128(n) if is_wide_integer(value.context, types) => Value::String(n.to_string()),
+            Primitive::I128(n) => json!(*n as i64),
+            Primitive::U256(bytes) | Primitive::I256(bytes) => Value::String(format!("0x{}", hex::encode(bytes))),
+        },
+        ValueDef::BitSequence(bits) => Value::String(bits.iter().map(|b| if b { '1' } else { '0' }).collect()),
+    }
+}
+
+fn composite_to_json(composite: &Composite<u32>, types: &PortableRegistry) -> Value {
+    match composite {
+        Composite::Named(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(name, value)| (name.clone(), value_to_json(value, types)))
+                .collect::<Map<_, _>>(),
+        ),
+        Composite::Unnamed(values) => {
+            if let Some(bytes) = as_bytes(values, types) {
+                return match <[u8; 32]>::try_from(bytes.as_slice()) {
+                    Ok(account) => Value::String(AccountId32(account).to_string()),
+                    Err(_) => Value::String(format!("0x{}", hex::encode(bytes))),
+                };
+            }
+            match values.as_slice() {
+                // Newtype wrappers such as `AccountId32([u8; 32])` are flattened.
+                [single] => value_to_json(single, types),
+                values => Value::Array(values.iter().map(|v| value_to_json(v, types)).collect()),
+            }
+        }
+    }
+}
+
+fn as_bytes(values: &[scale_value::Value<u32>], types: &PortableRegistry) -> Option<Vec<u8>> {
+    if values.len() < 2 || !values.iter().all(|value| is_primitive(value.context, types, TypeDefPrimitive::U8)) {
+        return None;
+    }
+    values
+        .iter()
+        .map(|value| match value.value {
+            ValueDef::Primitive(Primitive::U128(n)) => u8::try_from(n).ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+fn is_primitive(type_id: u32, types: &PortableRegistry, expected: TypeDefPrimitive) -> bool {
+    matches!(types.resolve(type_id).map(|ty| &ty.type_def), Some(TypeDef::Primitive(p)) if *p == expected)
+}
+
+fn is_wide_integer(type_id: u32, types: &PortableRegistry) -> bool {
+    match types.resolve(type_id).map(|ty| &ty.type_def) {
+        Some(TypeDef::Primitive(primitive)) => matches!(
+            primitive,
+            TypeDefPrimitive::U64 | TypeDefPrimitive::U128 | TypeDefPrimitive::I64 | TypeDefPrimitive::I128
+        ),
+        Some(TypeDef::Compact(compact)) => is_wide_integer(compact.type_param.id, types),
+        _ => false,
+    }
+}